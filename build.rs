@@ -0,0 +1,42 @@
+//! Build script embedding the git commit into the binary for `swe-forge version`.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn git(args: &[&str]) -> Option<String> {
+    Command::new("git")
+        .args(args)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn main() {
+    let commit = git(&["rev-parse", "--short=12", "HEAD"]).unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=SWE_FORGE_GIT_COMMIT={commit}");
+
+    // Without any rerun-if-changed path cargo reruns this script on every
+    // build, so always watch the script itself.
+    println!("cargo:rerun-if-changed=build.rs");
+
+    // Resolve the git directories instead of assuming `.git/`: in a worktree
+    // or submodule `.git` is a file, and in a source tarball there is none.
+    // HEAD lives in the per-worktree dir, refs in the common dir.
+    let Some(git_dir) = git(&["rev-parse", "--git-dir"]).map(PathBuf::from) else {
+        return;
+    };
+    let common_dir = git(&["rev-parse", "--git-common-dir"])
+        .map(PathBuf::from)
+        .unwrap_or_else(|| git_dir.clone());
+
+    let watched = [
+        git_dir.join("HEAD"),
+        common_dir.join("refs").join("heads"),
+        common_dir.join("packed-refs"),
+    ];
+    for path in watched.iter().filter(|path| Path::new(path).exists()) {
+        println!("cargo:rerun-if-changed={}", path.display());
+    }
+}
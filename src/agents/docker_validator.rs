@@ -7,15 +7,24 @@
 //! 3. Executing basic validation (environment starts correctly)
 //! 4. Optionally executing the reference solution to verify it works
 //! 5. Cleaning up resources
+//!
+//! When shared install steps are configured, the prepared base environment is
+//! committed to a tagged image keyed by its `DockerfileConfig` (base image and
+//! install steps), and reused by every subsequent task with the same key.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
 
+use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::sync::Mutex;
 use tracing::{debug, info, warn};
 
+use crate::docker::DockerfileConfig;
 use crate::execution::docker_client::{ContainerConfig, DockerClient};
-use crate::execution::{get_execution_limits, Container};
+use crate::execution::{get_execution_limits, Container, ExecResult};
 
 use super::error::{AgentError, AgentResult};
 use super::task_executor::SyntheticTask;
@@ -35,6 +44,18 @@ pub struct DockerValidatorConfig {
     pub default_base_image: String,
     /// Network mode for containers.
     pub network_mode: String,
+    /// Install steps shared by all tasks on the same base image.
+    ///
+    /// Only `pre_install`, `packages` and `post_install` are used; the base
+    /// image is chosen per task. The prepared image is cached as a tagged image.
+    pub base_dockerfile: Option<DockerfileConfig>,
+    /// Whether to reuse prepared base images across tasks and runs.
+    ///
+    /// When disabled, the base image is rebuilt for every task, replacing any
+    /// existing `swe-forge-base:<key>` image.
+    pub cache_base_images: bool,
+    /// Network mode for the container that runs the shared install steps.
+    pub setup_network_mode: String,
 }
 
 impl Default for DockerValidatorConfig {
@@ -46,6 +67,9 @@ impl Default for DockerValidatorConfig {
             keep_containers: false,
             default_base_image: "python:3.11-slim".to_string(),
             network_mode: "none".to_string(),
+            base_dockerfile: None,
+            cache_base_images: true,
+            setup_network_mode: "bridge".to_string(),
         }
     }
 }
@@ -91,6 +115,213 @@ impl DockerValidatorConfig {
         self.network_mode = mode.into();
         self
     }
+
+    /// Set the install steps shared by all tasks on the same base image.
+    pub fn with_base_dockerfile(mut self, config: DockerfileConfig) -> Self {
+        self.base_dockerfile = Some(config);
+        self
+    }
+
+    /// Set whether prepared base images are cached across tasks.
+    pub fn with_base_image_cache(mut self, enabled: bool) -> Self {
+        self.cache_base_images = enabled;
+        self
+    }
+
+    /// Set the network mode used while running the shared install steps.
+    pub fn with_setup_network_mode(mut self, mode: impl Into<String>) -> Self {
+        self.setup_network_mode = mode.into();
+        self
+    }
+}
+
+/// Repository name used for cached base images.
+const BASE_IMAGE_CACHE_REPO: &str = "swe-forge-base";
+
+/// Computes the cache key for a prepared base image.
+///
+/// Only the base image and install steps contribute, so tasks that resolve to
+/// the same base environment share a key and therefore the same cached image.
+pub fn base_image_cache_key(config: &DockerfileConfig) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(config.base_image.as_bytes());
+    for (section, items) in [
+        ("pre_install", &config.pre_install),
+        ("packages", &config.packages),
+        ("post_install", &config.post_install),
+    ] {
+        hasher.update(b"\0");
+        hasher.update(section.as_bytes());
+        for item in items {
+            hasher.update(b"\0");
+            hasher.update(item.as_bytes());
+        }
+    }
+    hex::encode(hasher.finalize())[..16].to_string()
+}
+
+/// Builds prepared base images for `DockerValidatorAgent`.
+///
+/// Abstracted so the agent's image cache can be exercised without a Docker daemon.
+#[async_trait]
+pub trait BaseImageBuilder: Send + Sync {
+    /// Whether the image is available locally.
+    async fn image_exists(&self, image: &str) -> bool;
+
+    /// Runs `setup_commands` in a container created from `config` and commits
+    /// the result as `tag`.
+    async fn build(
+        &self,
+        config: ContainerConfig,
+        setup_commands: &[String],
+        tag: &str,
+    ) -> AgentResult<()>;
+}
+
+/// `BaseImageBuilder` that runs the install steps in a Docker container.
+pub struct DockerBaseImageBuilder {
+    docker_client: Arc<DockerClient>,
+}
+
+impl DockerBaseImageBuilder {
+    /// Creates a builder backed by the given Docker client.
+    pub fn new(docker_client: Arc<DockerClient>) -> Self {
+        Self { docker_client }
+    }
+
+    /// Starts the setup container, runs the install steps and commits it.
+    async fn run_setup(
+        &self,
+        container: &mut Container,
+        setup_commands: &[String],
+        tag: &str,
+    ) -> AgentResult<()> {
+        container.start(&self.docker_client).await.map_err(|e| {
+            AgentError::GenerationFailed(format!("Failed to start setup container: {}", e))
+        })?;
+
+        for (i, cmd) in setup_commands.iter().enumerate() {
+            debug!(step = i + 1, command = %cmd, "Executing base setup step");
+            let result = container
+                .exec(&self.docker_client, &["sh", "-c", cmd])
+                .await
+                .map_err(|e| {
+                    AgentError::GenerationFailed(format!("Base setup step {} failed: {}", i + 1, e))
+                })?;
+
+            if result.exit_code != 0 {
+                return Err(AgentError::GenerationFailed(format!(
+                    "Base setup step '{}' failed with exit code {}: {}",
+                    cmd, result.exit_code, result.stderr
+                )));
+            }
+        }
+
+        let (repo, tag) = tag.rsplit_once(':').unwrap_or((tag, "latest"));
+        self.docker_client
+            .commit_container(container.id(), repo, tag)
+            .await
+            .map_err(|e| {
+                AgentError::GenerationFailed(format!("Failed to commit base image: {}", e))
+            })?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl BaseImageBuilder for DockerBaseImageBuilder {
+    async fn image_exists(&self, image: &str) -> bool {
+        self.docker_client.image_exists(image).await
+    }
+
+    async fn build(
+        &self,
+        config: ContainerConfig,
+        setup_commands: &[String],
+        tag: &str,
+    ) -> AgentResult<()> {
+        let mut container = Container::new(&self.docker_client, config)
+            .await
+            .map_err(|e| {
+                AgentError::GenerationFailed(format!("Failed to create setup container: {}", e))
+            })?;
+
+        let outcome = self.run_setup(&mut container, setup_commands, tag).await;
+        if let Err(e) = container.cleanup(&self.docker_client).await {
+            warn!(error = %e, "Failed to cleanup setup container");
+        }
+        outcome
+    }
+}
+
+/// Details of the reference solution step that failed in-container.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SolutionFailure {
+    /// 1-based index of the failing solution step.
+    pub step: usize,
+    /// Command that failed.
+    pub command: String,
+    /// Exit code of the failing command.
+    pub exit_code: i64,
+    /// Names of failing tests recognized in the command output.
+    pub failing_tests: Vec<String>,
+    /// Standard output of the failing command.
+    pub stdout: String,
+    /// Standard error of the failing command.
+    pub stderr: String,
+}
+
+impl SolutionFailure {
+    /// Builds a failure report from the result of a solution step.
+    pub fn from_exec(step: usize, command: impl Into<String>, exec: &ExecResult) -> Self {
+        let mut failing_tests = extract_failing_tests(&exec.stdout);
+        for name in extract_failing_tests(&exec.stderr) {
+            if !failing_tests.contains(&name) {
+                failing_tests.push(name);
+            }
+        }
+
+        Self {
+            step,
+            command: command.into(),
+            exit_code: exec.exit_code,
+            failing_tests,
+            stdout: exec.stdout.clone(),
+            stderr: exec.stderr.clone(),
+        }
+    }
+}
+
+/// Extracts failing test names from common test runner output.
+///
+/// Recognizes pytest (`FAILED path::test - reason`), cargo test
+/// (`test name ... FAILED`) and go test (`--- FAIL: TestName (0.00s)`) lines.
+pub fn extract_failing_tests(output: &str) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+
+    for line in output.lines() {
+        let line = line.trim();
+        let name = if let Some(rest) = line.strip_prefix("FAILED ") {
+            rest.split(" - ").next().map(str::trim)
+        } else if let Some(rest) = line.strip_prefix("--- FAIL: ") {
+            rest.split_whitespace().next()
+        } else if line.starts_with("test ") && line.ends_with(" ... FAILED") {
+            line.strip_prefix("test ")
+                .and_then(|rest| rest.strip_suffix(" ... FAILED"))
+                .map(str::trim)
+        } else {
+            None
+        };
+
+        if let Some(name) = name.filter(|n| !n.is_empty()) {
+            if !names.iter().any(|existing| existing == name) {
+                names.push(name.to_string());
+            }
+        }
+    }
+
+    names
 }
 
 /// Result of Docker validation.
@@ -112,6 +343,12 @@ pub struct DockerValidationResult {
     pub error: Option<String>,
     /// Container ID used for validation.
     pub container_id: Option<String>,
+    /// Whether the prepared base image was served from the cache.
+    #[serde(default)]
+    pub base_image_cache_hit: bool,
+    /// Details of the failing solution step, when solution validation failed.
+    #[serde(default)]
+    pub solution_failure: Option<SolutionFailure>,
 }
 
 impl DockerValidationResult {
@@ -126,6 +363,8 @@ impl DockerValidationResult {
             duration_ms,
             error: None,
             container_id,
+            base_image_cache_hit: false,
+            solution_failure: None,
         }
     }
 
@@ -140,6 +379,8 @@ impl DockerValidationResult {
             duration_ms,
             error: Some(error.into()),
             container_id: None,
+            base_image_cache_hit: false,
+            solution_failure: None,
         }
     }
 
@@ -153,12 +394,23 @@ impl DockerValidationResult {
         }
         self
     }
+
+    /// Attaches details of the failing solution step.
+    pub fn with_solution_failure(mut self, failure: SolutionFailure) -> Self {
+        self.solution_failure = Some(failure);
+        self
+    }
 }
 
 /// Agent that validates synthetic tasks can run in Docker containers.
 pub struct DockerValidatorAgent {
     docker_client: Arc<DockerClient>,
     config: DockerValidatorConfig,
+    /// Builds the prepared base images.
+    base_image_builder: Arc<dyn BaseImageBuilder>,
+    /// Per-key locks so concurrent validations with the same
+    /// `base_image_cache_key` wait for a single base image build.
+    base_image_builds: Mutex<HashMap<String, Arc<Mutex<()>>>>,
 }
 
 impl std::fmt::Debug for DockerValidatorAgent {
@@ -176,17 +428,28 @@ impl DockerValidatorAgent {
     /// Creates a new Docker validator agent.
     pub fn new(docker_client: Arc<DockerClient>, config: DockerValidatorConfig) -> Self {
         Self {
+            base_image_builder: Arc::new(DockerBaseImageBuilder::new(docker_client.clone())),
             docker_client,
             config,
+            base_image_builds: Mutex::new(HashMap::new()),
         }
     }
 
+    /// Replaces the builder used to prepare cached base images.
+    pub fn with_base_image_builder(mut self, builder: Arc<dyn BaseImageBuilder>) -> Self {
+        self.base_image_builder = builder;
+        self
+    }
+
     /// Creates a new agent with default configuration.
     ///
+    /// Connects to the daemon named by `DOCKER_HOST` (with `DOCKER_TLS_VERIFY`
+    /// and `DOCKER_CERT_PATH`), or the local socket when it is unset.
+    ///
     /// # Errors
-    /// Returns error if Docker daemon is not available.
+    /// Returns error if the Docker environment is invalid or the daemon is not available.
     pub fn with_defaults() -> AgentResult<Self> {
-        let docker_client = DockerClient::new().map_err(|e| {
+        let docker_client = DockerClient::from_env().map_err(|e| {
             AgentError::ConfigurationError(format!("Failed to connect to Docker: {}", e))
         })?;
         Ok(Self::new(
@@ -211,7 +474,7 @@ impl DockerValidatorAgent {
         );
 
         // Build container configuration from task
-        let container_config = self.build_container_config(task);
+        let mut container_config = self.build_container_config(task);
 
         // Ensure image exists
         if !self
@@ -228,6 +491,24 @@ impl DockerValidatorAgent {
                 })?;
         }
 
+        // Swap in the prepared base image when shared install steps are configured
+        let mut base_image_cache_hit = false;
+        if let Some(base) = self.base_dockerfile_config(&container_config.image) {
+            match self.prepare_base_image(&base, &container_config).await {
+                Ok((image, cache_hit)) => {
+                    container_config.image = image;
+                    base_image_cache_hit = cache_hit;
+                }
+                Err(e) => {
+                    let duration_ms = start_time.elapsed().as_millis() as u64;
+                    return Ok(DockerValidationResult::failure(
+                        format!("Failed to prepare base image: {}", e),
+                        duration_ms,
+                    ));
+                }
+            }
+        }
+
         // Create and start container
         let mut container = match Container::new(&self.docker_client, container_config).await {
             Ok(c) => c,
@@ -268,19 +549,23 @@ impl DockerValidatorAgent {
             start_time.elapsed().as_millis() as u64,
             Some(container_id.clone()),
         );
+        result.base_image_cache_hit = base_image_cache_hit;
 
         // Optionally validate the reference solution
         if self.config.validate_solution && !task.hidden_solution.reference_commands.is_empty() {
             match self.validate_solution(&container, task).await {
-                Ok((exit_code, output)) => {
+                Ok((exit_code, output, failure)) => {
                     let validated = exit_code == 0;
                     result = result.with_solution_result(validated, exit_code, output);
-                    if !validated {
+                    if let Some(failure) = failure {
                         warn!(
                             task_id = %task.id,
                             exit_code = exit_code,
+                            step = failure.step,
+                            failing_tests = ?failure.failing_tests,
                             "Solution validation failed"
                         );
+                        result = result.with_solution_failure(failure);
                     }
                 }
                 Err(e) => {
@@ -313,6 +598,85 @@ impl DockerValidatorAgent {
             || self.docker_client.pull_image("alpine:latest").await.is_ok()
     }
 
+    /// Shared install steps for a task's base image, if any are configured.
+    fn base_dockerfile_config(&self, base_image: &str) -> Option<DockerfileConfig> {
+        let shared = self.config.base_dockerfile.as_ref()?;
+        let config = DockerfileConfig {
+            base_image: base_image.to_string(),
+            packages: shared.packages.clone(),
+            pre_install: shared.pre_install.clone(),
+            post_install: shared.post_install.clone(),
+            ..DockerfileConfig::default()
+        };
+        (!config.install_commands().is_empty()).then_some(config)
+    }
+
+    /// Resolves the prepared base image for a task container.
+    ///
+    /// Returns the image to run the task on and whether it came from the cache.
+    /// An existing `swe-forge-base:<key>` image is reused, including one built by
+    /// an earlier run or another agent. On a miss, the install steps are executed
+    /// in a resource-limited container that is then committed under that tag.
+    /// Concurrent callers with the same key wait for a single build.
+    async fn prepare_base_image(
+        &self,
+        base: &DockerfileConfig,
+        task_config: &ContainerConfig,
+    ) -> AgentResult<(String, bool)> {
+        let key = base_image_cache_key(base);
+        let tag = format!("{}:{}", BASE_IMAGE_CACHE_REPO, key);
+
+        if !self.config.cache_base_images {
+            self.build_base_image(base, task_config, &key, &tag).await?;
+            return Ok((tag, false));
+        }
+
+        let build_lock = self
+            .base_image_builds
+            .lock()
+            .await
+            .entry(key.clone())
+            .or_default()
+            .clone();
+        let _build = build_lock.lock().await;
+
+        if self.base_image_builder.image_exists(&tag).await {
+            debug!(image = %tag, "Reusing cached base image");
+            return Ok((tag, true));
+        }
+
+        self.build_base_image(base, task_config, &key, &tag).await?;
+        Ok((tag, false))
+    }
+
+    /// Builds the prepared base image for a cache key.
+    async fn build_base_image(
+        &self,
+        base: &DockerfileConfig,
+        task_config: &ContainerConfig,
+        key: &str,
+        tag: &str,
+    ) -> AgentResult<()> {
+        info!(
+            base_image = %base.base_image,
+            cache_key = %key,
+            "Preparing base image"
+        );
+
+        // Install steps need network access even when task containers do not.
+        let setup_config = ContainerConfig::new(
+            format!("swe_forge-base-{}-{}", key, uuid::Uuid::new_v4().simple()),
+            base.base_image.clone(),
+        )
+        .with_limits(task_config.limits.clone())
+        .with_working_dir("/workspace")
+        .with_network_mode(self.config.setup_network_mode.clone());
+
+        self.base_image_builder
+            .build(setup_config, &base.install_commands(), tag)
+            .await
+    }
+
     /// Builds container configuration from a synthetic task.
     fn build_container_config(&self, task: &SyntheticTask) -> ContainerConfig {
         let difficulty_str = format!("{:?}", task.difficulty.level).to_lowercase();
@@ -396,11 +760,14 @@ impl DockerValidatorAgent {
     }
 
     /// Validates the reference solution runs successfully.
+    ///
+    /// Returns the last exit code, the combined output of all steps, and the
+    /// details of the failing step if any step exited non-zero.
     async fn validate_solution(
         &self,
         container: &Container,
         task: &SyntheticTask,
-    ) -> AgentResult<(i64, String)> {
+    ) -> AgentResult<(i64, String, Option<SolutionFailure>)> {
         let mut combined_output = String::new();
         let mut last_exit_code = 0i64;
 
@@ -429,11 +796,12 @@ impl DockerValidatorAgent {
 
             // Stop on first failure
             if result.exit_code != 0 {
-                break;
+                let failure = SolutionFailure::from_exec(i + 1, cmd.as_str(), &result);
+                return Ok((last_exit_code, combined_output, Some(failure)));
             }
         }
 
-        Ok((last_exit_code, combined_output))
+        Ok((last_exit_code, combined_output, None))
     }
 
     /// Cleans up a container.
@@ -480,6 +848,9 @@ mod tests {
         assert_eq!(config.validation_timeout_seconds, 300);
         assert!(config.validate_solution);
         assert!(!config.keep_containers);
+        assert!(config.base_dockerfile.is_none());
+        assert!(config.cache_base_images);
+        assert_eq!(config.setup_network_mode, "bridge");
     }
 
     #[test]
@@ -544,6 +915,218 @@ mod tests {
         assert_eq!(result.solution_exit_code, Some(1));
     }
 
+    /// Records builds instead of running them in Docker.
+    #[derive(Default)]
+    struct MockBaseImageBuilder {
+        builds: std::sync::Mutex<Vec<(String, Vec<String>, String)>>,
+    }
+
+    impl MockBaseImageBuilder {
+        fn build_count(&self) -> usize {
+            self.builds.lock().unwrap().len()
+        }
+    }
+
+    #[async_trait]
+    impl BaseImageBuilder for MockBaseImageBuilder {
+        async fn image_exists(&self, image: &str) -> bool {
+            self.builds
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|(_, _, tag)| tag == image)
+        }
+
+        async fn build(
+            &self,
+            config: ContainerConfig,
+            setup_commands: &[String],
+            tag: &str,
+        ) -> AgentResult<()> {
+            // Yield so concurrent callers overlap with the build.
+            tokio::task::yield_now().await;
+            self.builds.lock().unwrap().push((
+                config.network_mode.unwrap_or_default(),
+                setup_commands.to_vec(),
+                tag.to_string(),
+            ));
+            Ok(())
+        }
+    }
+
+    fn shared_install() -> DockerfileConfig {
+        DockerfileConfig {
+            packages: vec!["git".to_string()],
+            post_install: vec!["pip install pytest".to_string()],
+            ..DockerfileConfig::default()
+        }
+    }
+
+    fn agent_with_mock_builder(
+        config: DockerValidatorConfig,
+    ) -> (DockerValidatorAgent, Arc<MockBaseImageBuilder>) {
+        let client = Arc::new(DockerClient::new().expect("client construction is lazy"));
+        let builder = Arc::new(MockBaseImageBuilder::default());
+        let agent =
+            DockerValidatorAgent::new(client, config).with_base_image_builder(builder.clone());
+        (agent, builder)
+    }
+
+    #[test]
+    fn test_base_image_cache_key() {
+        let base = DockerfileConfig {
+            base_image: "python:3.11-slim".to_string(),
+            ..shared_install()
+        };
+        let key = base_image_cache_key(&base);
+        assert_eq!(key.len(), 16);
+
+        // Task-specific fields do not affect the key.
+        let other_task = DockerfileConfig {
+            task_id: "other".to_string(),
+            category: "debugging".to_string(),
+            ..base.clone()
+        };
+        assert_eq!(key, base_image_cache_key(&other_task));
+
+        let other_image = DockerfileConfig {
+            base_image: "node:20-slim".to_string(),
+            ..base.clone()
+        };
+        assert_ne!(key, base_image_cache_key(&other_image));
+
+        // Moving a step between sections changes the key.
+        let moved = DockerfileConfig {
+            pre_install: base.post_install.clone(),
+            post_install: Vec::new(),
+            ..base.clone()
+        };
+        assert_ne!(key, base_image_cache_key(&moved));
+    }
+
+    #[tokio::test]
+    async fn test_identical_tasks_share_cached_base_image() {
+        let config = DockerValidatorConfig::new().with_base_dockerfile(shared_install());
+        let (agent, builder) = agent_with_mock_builder(config);
+
+        let first = agent.build_container_config(&create_test_task());
+        let second = agent.build_container_config(&create_test_task());
+        let first_base = agent.base_dockerfile_config(&first.image).unwrap();
+        let second_base = agent.base_dockerfile_config(&second.image).unwrap();
+
+        let (first_result, second_result) = tokio::join!(
+            agent.prepare_base_image(&first_base, &first),
+            agent.prepare_base_image(&second_base, &second),
+        );
+        let (first_image, first_hit) = first_result.unwrap();
+        let (second_image, second_hit) = second_result.unwrap();
+
+        assert_eq!(first_image, second_image);
+        assert_eq!(builder.build_count(), 1);
+        assert!(first_hit != second_hit);
+
+        let builds = builder.builds.lock().unwrap();
+        let (network_mode, commands, tag) = &builds[0];
+        assert_eq!(network_mode, "bridge");
+        assert_eq!(commands, &shared_install().install_commands());
+        assert_eq!(tag, &first_image);
+    }
+
+    #[tokio::test]
+    async fn test_existing_tagged_base_image_is_reused() {
+        let config = DockerValidatorConfig::new().with_base_dockerfile(shared_install());
+        let (agent, builder) = agent_with_mock_builder(config);
+        let container = agent.build_container_config(&create_test_task());
+        let base = agent.base_dockerfile_config(&container.image).unwrap();
+        agent.prepare_base_image(&base, &container).await.unwrap();
+
+        // A fresh agent (e.g. after a restart) finds the image by its tag.
+        let restarted = DockerValidatorAgent::new(
+            Arc::new(DockerClient::new().expect("client construction is lazy")),
+            DockerValidatorConfig::new().with_base_dockerfile(shared_install()),
+        )
+        .with_base_image_builder(builder.clone());
+        let (image, hit) = restarted
+            .prepare_base_image(&base, &container)
+            .await
+            .unwrap();
+
+        assert!(hit);
+        assert_eq!(
+            image,
+            format!("{}:{}", BASE_IMAGE_CACHE_REPO, base_image_cache_key(&base))
+        );
+        assert_eq!(builder.build_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_base_image_cache_disabled_rebuilds() {
+        let config = DockerValidatorConfig::new()
+            .with_base_dockerfile(shared_install())
+            .with_base_image_cache(false);
+        let (agent, builder) = agent_with_mock_builder(config);
+
+        let container = agent.build_container_config(&create_test_task());
+        let base = agent.base_dockerfile_config(&container.image).unwrap();
+        for _ in 0..2 {
+            let (_, hit) = agent.prepare_base_image(&base, &container).await.unwrap();
+            assert!(!hit);
+        }
+        assert_eq!(builder.build_count(), 2);
+    }
+
+    #[test]
+    fn test_base_dockerfile_config_requires_install_steps() {
+        let (agent, _) = agent_with_mock_builder(DockerValidatorConfig::new());
+        assert!(agent.base_dockerfile_config("python:3.11-slim").is_none());
+
+        let config = DockerValidatorConfig::new().with_base_dockerfile(DockerfileConfig::default());
+        let (agent, _) = agent_with_mock_builder(config);
+        assert!(agent.base_dockerfile_config("python:3.11-slim").is_none());
+    }
+
+    #[test]
+    fn test_solution_failure_from_failing_run() {
+        let exec = ExecResult {
+            exit_code: 1,
+            stdout: "tests/test_parser.py::test_ok PASSED\n\
+                     FAILED tests/test_parser.py::test_empty_input - AssertionError\n\
+                     FAILED tests/test_parser.py::test_unicode - KeyError: 'x'\n"
+                .to_string(),
+            stderr: "warning: deprecated option\n".to_string(),
+        };
+
+        let failure = SolutionFailure::from_exec(2, "pytest tests/", &exec);
+        assert_eq!(failure.step, 2);
+        assert_eq!(failure.command, "pytest tests/");
+        assert_eq!(failure.exit_code, 1);
+        assert_eq!(
+            failure.failing_tests,
+            vec![
+                "tests/test_parser.py::test_empty_input",
+                "tests/test_parser.py::test_unicode"
+            ]
+        );
+        assert!(failure.stderr.contains("deprecated"));
+
+        let result = DockerValidationResult::success(1000, None)
+            .with_solution_result(false, 1, exec.stdout.clone())
+            .with_solution_failure(failure.clone());
+        assert!(!result.passed);
+        assert_eq!(result.solution_failure, Some(failure));
+    }
+
+    #[test]
+    fn test_extract_failing_tests_other_runners() {
+        let cargo = "test parser::tests::roundtrip ... ok\ntest parser::tests::empty ... FAILED\n";
+        assert_eq!(extract_failing_tests(cargo), vec!["parser::tests::empty"]);
+
+        let go = "=== RUN   TestParse\n--- FAIL: TestParse (0.00s)\nFAIL\n";
+        assert_eq!(extract_failing_tests(go), vec!["TestParse"]);
+
+        assert!(extract_failing_tests("all good\n").is_empty());
+    }
+
     #[test]
     fn test_determine_base_image_python() {
         let _config = DockerValidatorConfig::default();
@@ -3,6 +3,7 @@
 //! Defines comprehensive error types for agent operations including
 //! generation, validation, and orchestration failures.
 
+use std::time::Duration;
 use thiserror::Error;
 
 /// Errors that can occur during agent operations.
@@ -22,12 +23,16 @@ pub enum AgentError {
 
     /// Error from the LLM provider.
     #[error("LLM error: {0}")]
-    LlmError(String),
+    LlmError(#[source] crate::error::LlmError),
 
     /// Error parsing LLM response.
     #[error("Failed to parse LLM response: {0}")]
     ResponseParseError(String),
 
+    /// The LLM response did not conform to the requested JSON schema.
+    #[error("LLM response does not match JSON schema: {}", errors.join("; "))]
+    SchemaViolation { errors: Vec<String> },
+
     /// Template not found.
     #[error("Template not found: {0}")]
     TemplateNotFound(String),
@@ -52,6 +57,10 @@ pub enum AgentError {
     #[error("Agent operation timed out after {seconds} seconds")]
     Timeout { seconds: u64 },
 
+    /// The upstream provider rejected the request with a rate limit (HTTP 429).
+    #[error("Rate limited{}", format_retry_after(.retry_after))]
+    RateLimited { retry_after: Option<Duration> },
+
     /// Validation threshold not met.
     #[error("Validation threshold not met: score {score:.2} < required {threshold:.2}")]
     ThresholdNotMet { score: f64, threshold: f64 },
@@ -73,9 +82,70 @@ pub enum AgentError {
     Json(#[from] serde_json::Error),
 }
 
+impl AgentError {
+    /// Returns `true` if the operation that produced this error is worth retrying.
+    ///
+    /// Rate limits, timeouts, transient network/server-side LLM failures,
+    /// interrupted IO and schema violations (the model may conform on a fresh
+    /// sample) are retryable. Configuration, validation and parse errors are
+    /// deterministic and will fail again on retry.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            AgentError::RateLimited { .. }
+            | AgentError::Timeout { .. }
+            | AgentError::SchemaViolation { .. } => true,
+            AgentError::LlmError(err) => is_transient_llm_error(err),
+            AgentError::Io(err) => is_transient_io(err.kind()),
+            _ => false,
+        }
+    }
+}
+
+/// Format the optional retry delay for the `RateLimited` display message.
+fn format_retry_after(retry_after: &Option<Duration>) -> String {
+    match retry_after {
+        Some(delay) => format!(", retry after {}s", delay.as_secs()),
+        None => String::new(),
+    }
+}
+
+/// Check whether an LLM error describes a transient failure.
+fn is_transient_llm_error(err: &crate::error::LlmError) -> bool {
+    use crate::error::LlmError;
+    match err {
+        LlmError::RequestFailed(_) | LlmError::Timeout(_) => true,
+        LlmError::ApiError { code, .. } => *code >= 500,
+        LlmError::Io(err) => is_transient_io(err.kind()),
+        _ => false,
+    }
+}
+
+/// Check whether an IO error kind is transient.
+fn is_transient_io(kind: std::io::ErrorKind) -> bool {
+    matches!(
+        kind,
+        std::io::ErrorKind::TimedOut
+            | std::io::ErrorKind::Interrupted
+            | std::io::ErrorKind::ConnectionReset
+            | std::io::ErrorKind::ConnectionAborted
+            | std::io::ErrorKind::WouldBlock
+    )
+}
+
 impl From<crate::error::LlmError> for AgentError {
     fn from(err: crate::error::LlmError) -> Self {
-        AgentError::LlmError(err.to_string())
+        match err {
+            crate::error::LlmError::RateLimited { retry_after, .. } => {
+                AgentError::RateLimited { retry_after }
+            }
+            crate::error::LlmError::ApiError { code: 429, .. } => {
+                AgentError::RateLimited { retry_after: None }
+            }
+            crate::error::LlmError::SchemaViolation { errors } => {
+                AgentError::SchemaViolation { errors }
+            }
+            other => AgentError::LlmError(other),
+        }
     }
 }
 
@@ -87,3 +157,95 @@ impl<T> From<tokio::sync::mpsc::error::SendError<T>> for AgentError {
 
 /// Result type alias for agent operations.
 pub type AgentResult<T> = Result<T, AgentError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::LlmError;
+
+    #[test]
+    fn test_retryable_variants() {
+        assert!(AgentError::Timeout { seconds: 30 }.is_retryable());
+        assert!(AgentError::RateLimited {
+            retry_after: Some(Duration::from_secs(5))
+        }
+        .is_retryable());
+        assert!(
+            AgentError::from(LlmError::RequestFailed("connection reset".to_string()))
+                .is_retryable()
+        );
+        assert!(AgentError::from(LlmError::ApiError {
+            code: 503,
+            message: "unavailable".to_string()
+        })
+        .is_retryable());
+        assert!(AgentError::Io(std::io::Error::from(std::io::ErrorKind::TimedOut)).is_retryable());
+    }
+
+    #[test]
+    fn test_non_retryable_variants() {
+        assert!(!AgentError::ConfigurationError("bad".to_string()).is_retryable());
+        assert!(!AgentError::ResponseParseError("bad json".to_string()).is_retryable());
+        assert!(!AgentError::ThresholdNotMet {
+            score: 0.2,
+            threshold: 0.5
+        }
+        .is_retryable());
+        assert!(!AgentError::from(LlmError::ApiError {
+            code: 400,
+            message: "bad request".to_string()
+        })
+        .is_retryable());
+        assert!(!AgentError::from(LlmError::MissingApiKey).is_retryable());
+        // A fresh sample may conform to the schema, so violations are retried.
+        let schema_err = AgentError::from(LlmError::SchemaViolation {
+            errors: vec!["$: missing required field 'title'".to_string()],
+        });
+        assert!(
+            matches!(schema_err, AgentError::SchemaViolation { ref errors } if errors.len() == 1)
+        );
+        assert!(schema_err.is_retryable());
+        assert!(!AgentError::Io(std::io::Error::from(std::io::ErrorKind::NotFound)).is_retryable());
+    }
+
+    #[test]
+    fn test_llm_error_kind_is_kept() {
+        let err = AgentError::from(LlmError::Timeout("upstream".to_string()));
+        assert!(matches!(err, AgentError::LlmError(LlmError::Timeout(_))));
+        assert!(err.is_retryable());
+
+        // Classification no longer depends on the wording of the message.
+        let err = AgentError::from(LlmError::ParseError("connection field missing".to_string()));
+        assert!(!err.is_retryable());
+    }
+
+    #[test]
+    fn test_rate_limited_from_llm_error() {
+        let err = AgentError::from(LlmError::RateLimited {
+            message: "slow down".to_string(),
+            retry_after: Some(Duration::from_secs(7)),
+        });
+        assert!(matches!(
+            err,
+            AgentError::RateLimited {
+                retry_after: Some(d)
+            } if d == Duration::from_secs(7)
+        ));
+
+        let err = AgentError::from(LlmError::ApiError {
+            code: 429,
+            message: "too many requests".to_string(),
+        });
+        assert!(matches!(err, AgentError::RateLimited { .. }));
+    }
+
+    #[test]
+    fn test_rate_limited_display() {
+        let err = AgentError::RateLimited {
+            retry_after: Some(Duration::from_secs(12)),
+        };
+        assert_eq!(err.to_string(), "Rate limited, retry after 12s");
+        let err = AgentError::RateLimited { retry_after: None };
+        assert_eq!(err.to_string(), "Rate limited");
+    }
+}
@@ -5,7 +5,10 @@ pub mod error;
 pub mod task_executor;
 pub mod task_validator;
 
-pub use docker_validator::{DockerValidationResult, DockerValidatorAgent, DockerValidatorConfig};
+pub use docker_validator::{
+    BaseImageBuilder, DockerBaseImageBuilder, DockerValidationResult, DockerValidatorAgent,
+    DockerValidatorConfig, SolutionFailure,
+};
 pub use error::{AgentError, AgentResult};
 pub use task_executor::{
     AntiMemorizationConfig, AutomatedCheck, CheckType, DifficultyScoring, HiddenSolution,
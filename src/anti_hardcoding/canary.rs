@@ -44,6 +44,22 @@ impl CanaryConfig {
             canary_uuid.as_hyphenated().to_string().to_uppercase()
         );
 
+        Self::from_id(canary_id)
+    }
+
+    /// Rebuild a canary from a previously recorded canary id.
+    ///
+    /// Used when scanning exported datasets, where only the id is stored in
+    /// task metadata; the hash is recomputed from it.
+    ///
+    /// # Arguments
+    /// * `canary_id` - The recorded canary identifier
+    ///
+    /// # Returns
+    /// A `CanaryConfig` with the recomputed hash
+    pub fn from_id(canary_id: impl Into<String>) -> Self {
+        let canary_id = canary_id.into();
+
         // Generate hash of the canary for verification
         let mut hasher = Sha256::new();
         hasher.update(canary_id.as_bytes());
@@ -211,6 +227,14 @@ mod tests {
         assert_ne!(canary1.canary_id, canary2.canary_id);
     }
 
+    #[test]
+    fn test_canary_from_id_matches_generated_hash() {
+        let generated = CanaryConfig::generate("task-123", 42);
+        let restored = CanaryConfig::from_id(generated.canary_id.clone());
+
+        assert_eq!(restored.canary_hash, generated.canary_hash);
+    }
+
     #[test]
     fn test_embed_canary() {
         let canary = CanaryConfig::generate("task-123", 42);
@@ -16,7 +16,7 @@
 //!
 //! ```ignore
 //! use swe_forge::anti_hardcoding::{
-//!     AntiHardcodingVerifier, CanaryConfig, ProcessValidationConfig
+//!     AntiHardcodingVerifier, CanaryConfig, ProcessValidationConfig, ScoringConfig
 //! };
 //!
 //! // Generate a canary for the task
@@ -27,8 +27,9 @@
 //!     .with_required_pattern(r"cargo test")
 //!     .with_forbidden_pattern(r"curl.*answer");
 //!
-//! // Create the verifier
-//! let verifier = AntiHardcodingVerifier::new(canary, process_config);
+//! // Create the verifier with the default score weighting
+//! let verifier =
+//!     AntiHardcodingVerifier::new(canary, process_config, ScoringConfig::default());
 //!
 //! // Verify model output
 //! let result = verifier.verify("Model's response here");
@@ -41,12 +42,62 @@ pub mod sealed;
 // Re-export main types for convenient access
 pub use canary::{detect_contamination, embed_canary, CanaryConfig, ContaminationResult};
 pub use process_validation::{
-    CommandExecution, ProcessTracer, ProcessValidationConfig, ProcessValidationResult,
+    CommandExecution, ProcessTracer, ProcessValidationConfig, ProcessValidationIssue,
+    ProcessValidationResult,
 };
 pub use sealed::{SealError, SealedData, SealedParameters};
 
 use serde::{Deserialize, Serialize};
 
+/// Weighting applied when combining contamination and process scores.
+///
+/// The defaults match the historical fixed penalties: confirmed contamination
+/// keeps 10% of the score, a partial canary match keeps 70%, and confidence
+/// above 0.5 costs up to a further 20%.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ScoringConfig {
+    /// Multiplier applied when the full canary is found.
+    pub contaminated_factor: f64,
+    /// Multiplier applied on a partial canary match.
+    pub partial_match_factor: f64,
+    /// Contamination confidence above which the extra penalty applies.
+    pub confidence_threshold: f64,
+    /// Penalty per unit of confidence above the threshold.
+    pub confidence_penalty_weight: f64,
+}
+
+impl Default for ScoringConfig {
+    fn default() -> Self {
+        Self {
+            contaminated_factor: 0.1,
+            partial_match_factor: 0.7,
+            confidence_threshold: 0.5,
+            confidence_penalty_weight: 0.4,
+        }
+    }
+}
+
+impl ScoringConfig {
+    /// Create a scoring configuration with the default weighting.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the score multipliers for confirmed and partial contamination.
+    pub fn with_contamination_factors(mut self, contaminated: f64, partial_match: f64) -> Self {
+        self.contaminated_factor = contaminated;
+        self.partial_match_factor = partial_match;
+        self
+    }
+
+    /// Set the confidence threshold and the penalty weight applied above it.
+    pub fn with_confidence_penalty(mut self, threshold: f64, weight: f64) -> Self {
+        self.confidence_threshold = threshold;
+        self.confidence_penalty_weight = weight;
+        self
+    }
+}
+
 /// Combined verification result from all anti-hardcoding mechanisms.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VerificationResult {
@@ -92,6 +143,7 @@ impl VerificationResult {
 pub struct AntiHardcodingVerifier {
     canary: CanaryConfig,
     process_tracer: ProcessTracer,
+    scoring: ScoringConfig,
 }
 
 impl AntiHardcodingVerifier {
@@ -100,13 +152,19 @@ impl AntiHardcodingVerifier {
     /// # Arguments
     /// * `canary` - The canary configuration for contamination detection
     /// * `process_config` - Configuration for process validation rules
+    /// * `scoring` - Penalty weighting used for the combined score
     ///
     /// # Returns
     /// A new `AntiHardcodingVerifier` instance
-    pub fn new(canary: CanaryConfig, process_config: ProcessValidationConfig) -> Self {
+    pub fn new(
+        canary: CanaryConfig,
+        process_config: ProcessValidationConfig,
+        scoring: ScoringConfig,
+    ) -> Self {
         Self {
             canary,
             process_tracer: ProcessTracer::new(process_config),
+            scoring,
         }
     }
 
@@ -115,6 +173,11 @@ impl AntiHardcodingVerifier {
         &self.canary
     }
 
+    /// Get a reference to the scoring configuration.
+    pub fn scoring(&self) -> &ScoringConfig {
+        &self.scoring
+    }
+
     /// Get a mutable reference to the process tracer for recording executions.
     pub fn process_tracer_mut(&mut self) -> &mut ProcessTracer {
         &mut self.process_tracer
@@ -135,6 +198,19 @@ impl AntiHardcodingVerifier {
         self.process_tracer.record(execution);
     }
 
+    /// Record a command execution and report a forbidden command immediately.
+    ///
+    /// Convenience method that delegates to [`ProcessTracer::record_and_check`].
+    ///
+    /// # Arguments
+    /// * `execution` - The command execution record to add
+    pub fn record_and_check(
+        &mut self,
+        execution: CommandExecution,
+    ) -> Option<ProcessValidationIssue> {
+        self.process_tracer.record_and_check(execution)
+    }
+
     /// Verify model output against all anti-hardcoding mechanisms.
     ///
     /// This performs:
@@ -171,7 +247,7 @@ impl AntiHardcodingVerifier {
         issues.extend(process_validation.issues.clone());
 
         // Calculate combined score
-        let score = calculate_combined_score(&contamination, &process_validation);
+        let score = calculate_combined_score(&contamination, &process_validation, &self.scoring);
 
         // Determine overall validity
         let valid = !contamination.contaminated && process_validation.valid;
@@ -190,7 +266,11 @@ impl AntiHardcodingVerifier {
     /// # Arguments
     /// * `canary` - The canary configuration for contamination detection
     pub fn with_canary_only(canary: CanaryConfig) -> Self {
-        Self::new(canary, ProcessValidationConfig::default())
+        Self::new(
+            canary,
+            ProcessValidationConfig::default(),
+            ScoringConfig::default(),
+        )
     }
 }
 
@@ -198,20 +278,22 @@ impl AntiHardcodingVerifier {
 fn calculate_combined_score(
     contamination: &ContaminationResult,
     process_validation: &ProcessValidationResult,
+    scoring: &ScoringConfig,
 ) -> f64 {
     // Start with process validation score
     let mut score = process_validation.score;
 
     // Heavily penalize contamination
     if contamination.contaminated {
-        score *= 0.1; // 90% penalty for confirmed contamination
+        score *= scoring.contaminated_factor;
     } else if contamination.partial_match {
-        score *= 0.7; // 30% penalty for partial match
+        score *= scoring.partial_match_factor;
     }
 
     // Adjust based on contamination confidence
-    if contamination.confidence > 0.5 {
-        let penalty = (contamination.confidence - 0.5) * 0.4; // Up to 20% additional penalty
+    if contamination.confidence > scoring.confidence_threshold {
+        let penalty = (contamination.confidence - scoring.confidence_threshold)
+            * scoring.confidence_penalty_weight;
         score *= 1.0 - penalty;
     }
 
@@ -227,7 +309,8 @@ mod tests {
         let canary = CanaryConfig::generate("test-task", 123);
         let config = ProcessValidationConfig::new().with_required_pattern(r"cargo test");
 
-        let verifier = AntiHardcodingVerifier::new(canary.clone(), config);
+        let verifier =
+            AntiHardcodingVerifier::new(canary.clone(), config, ScoringConfig::default());
 
         assert_eq!(verifier.canary().canary_id, canary.canary_id);
     }
@@ -237,7 +320,7 @@ mod tests {
         let canary = CanaryConfig::generate("test-task", 123);
         let config = ProcessValidationConfig::default();
 
-        let verifier = AntiHardcodingVerifier::new(canary, config);
+        let verifier = AntiHardcodingVerifier::new(canary, config, ScoringConfig::default());
         let result = verifier.verify("This is a clean output with no canary");
 
         assert!(result.valid);
@@ -250,7 +333,8 @@ mod tests {
         let canary = CanaryConfig::generate("test-task", 123);
         let config = ProcessValidationConfig::default();
 
-        let verifier = AntiHardcodingVerifier::new(canary.clone(), config);
+        let verifier =
+            AntiHardcodingVerifier::new(canary.clone(), config, ScoringConfig::default());
         let contaminated_output = format!("Output containing {} the canary", canary.canary_id);
         let result = verifier.verify(&contaminated_output);
 
@@ -265,7 +349,7 @@ mod tests {
         let canary = CanaryConfig::generate("test-task", 123);
         let config = ProcessValidationConfig::new().with_required_pattern(r"^git\s+");
 
-        let mut verifier = AntiHardcodingVerifier::new(canary, config);
+        let mut verifier = AntiHardcodingVerifier::new(canary, config, ScoringConfig::default());
 
         // Record a matching execution
         verifier.record_execution(CommandExecution::new(
@@ -288,7 +372,7 @@ mod tests {
         let canary = CanaryConfig::generate("test-task", 123);
         let config = ProcessValidationConfig::new().with_required_pattern(r"cargo test");
 
-        let mut verifier = AntiHardcodingVerifier::new(canary, config);
+        let mut verifier = AntiHardcodingVerifier::new(canary, config, ScoringConfig::default());
 
         // Record a non-matching execution
         verifier.record_execution(CommandExecution::new(
@@ -367,7 +451,11 @@ mod tests {
         };
         let good_process = ProcessValidationResult::success();
 
-        let score = calculate_combined_score(&clean_contamination, &good_process);
+        let score = calculate_combined_score(
+            &clean_contamination,
+            &good_process,
+            &ScoringConfig::default(),
+        );
         assert_eq!(score, 1.0);
 
         // Contaminated case
@@ -378,7 +466,49 @@ mod tests {
             confidence: 1.0,
         };
 
-        let score = calculate_combined_score(&contaminated, &good_process);
+        let score =
+            calculate_combined_score(&contaminated, &good_process, &ScoringConfig::default());
         assert!(score < 0.15); // Heavy penalty for contamination
     }
+
+    #[test]
+    fn test_default_scoring_matches_previous_penalties() {
+        let partial = ContaminationResult {
+            contaminated: false,
+            canary_found: false,
+            partial_match: true,
+            confidence: 0.75,
+        };
+        let good_process = ProcessValidationResult::success();
+
+        let score = calculate_combined_score(&partial, &good_process, &ScoringConfig::default());
+        assert!((score - 0.7 * 0.9).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_stricter_scoring_lowers_partial_match_score() {
+        let canary = CanaryConfig::generate("test-task", 789);
+        let fragment = &canary.canary_id[..canary.canary_id.len() / 2];
+        let output = format!("Output mentioning {}", fragment);
+
+        let default_verifier = AntiHardcodingVerifier::new(
+            canary.clone(),
+            ProcessValidationConfig::default(),
+            ScoringConfig::default(),
+        );
+        let strict_verifier = AntiHardcodingVerifier::new(
+            canary,
+            ProcessValidationConfig::default(),
+            ScoringConfig::new()
+                .with_contamination_factors(0.0, 0.3)
+                .with_confidence_penalty(0.2, 1.0),
+        );
+
+        let lenient = default_verifier.verify(&output);
+        let strict = strict_verifier.verify(&output);
+
+        assert!(lenient.contamination.partial_match);
+        assert!(!lenient.contamination.contaminated);
+        assert!(strict.score < lenient.score);
+    }
 }
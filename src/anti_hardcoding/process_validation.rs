@@ -128,21 +128,57 @@ impl ProcessValidationConfig {
 /// Tracer for recording and validating command executions.
 pub struct ProcessTracer {
     config: ProcessValidationConfig,
+    /// Compiled required patterns, paired with their source.
+    required: Vec<(String, Regex)>,
+    /// Compiled forbidden patterns, paired with their source.
+    forbidden: Vec<(String, Regex)>,
+    /// Patterns that failed to compile, with the regex error.
+    invalid_patterns: Vec<String>,
     executions: Vec<CommandExecution>,
 }
 
 impl ProcessTracer {
     /// Create a new process tracer with the given configuration.
     ///
+    /// Patterns are compiled once here. Invalid patterns are logged, exposed
+    /// through [`ProcessTracer::invalid_patterns`] and reported as issues by
+    /// [`ProcessTracer::validate`].
+    ///
     /// # Arguments
     /// * `config` - Validation configuration to use
     pub fn new(config: ProcessValidationConfig) -> Self {
+        let mut invalid_patterns = Vec::new();
+        let mut compile = |patterns: &[String]| {
+            patterns
+                .iter()
+                .filter_map(|pattern| match Regex::new(pattern) {
+                    Ok(re) => Some((pattern.clone(), re)),
+                    Err(err) => {
+                        tracing::warn!(pattern = %pattern, error = %err, "Invalid command pattern");
+                        invalid_patterns
+                            .push(format!("Invalid command pattern '{}': {}", pattern, err));
+                        None
+                    }
+                })
+                .collect::<Vec<_>>()
+        };
+        let required = compile(&config.required_patterns);
+        let forbidden = compile(&config.forbidden_patterns);
+
         Self {
             config,
+            required,
+            forbidden,
+            invalid_patterns,
             executions: Vec::new(),
         }
     }
 
+    /// Patterns from the configuration that are not valid regexes.
+    pub fn invalid_patterns(&self) -> &[String] {
+        &self.invalid_patterns
+    }
+
     /// Record a command execution.
     ///
     /// # Arguments
@@ -151,6 +187,35 @@ impl ProcessTracer {
         self.executions.push(execution);
     }
 
+    /// Record a command execution and check it against forbidden patterns.
+    ///
+    /// Intended for live command feeds: the execution is recorded as with
+    /// [`ProcessTracer::record`], and if it matches a forbidden pattern the
+    /// issue is returned immediately so the caller can abort the run instead
+    /// of waiting for [`ProcessTracer::validate`].
+    ///
+    /// # Arguments
+    /// * `execution` - The command execution record to add
+    ///
+    /// # Returns
+    /// The first forbidden-pattern issue matched by this command, if any
+    pub fn record_and_check(
+        &mut self,
+        execution: CommandExecution,
+    ) -> Option<ProcessValidationIssue> {
+        let issue = self
+            .forbidden
+            .iter()
+            .find(|(_, re)| re.is_match(&execution.command))
+            .map(|(pattern, _)| ProcessValidationIssue {
+                pattern: pattern.clone(),
+                command: execution.command.clone(),
+                command_index: self.executions.len(),
+            });
+        self.record(execution);
+        issue
+    }
+
     /// Get all recorded executions.
     pub fn executions(&self) -> &[CommandExecution] {
         &self.executions
@@ -171,7 +236,7 @@ impl ProcessTracer {
     /// # Returns
     /// A `ProcessValidationResult` containing validation status and details
     pub fn validate(&self) -> ProcessValidationResult {
-        let mut issues = Vec::new();
+        let mut issues = self.invalid_patterns.clone();
         let mut required_found: HashMap<String, bool> = HashMap::new();
         let mut forbidden_used: HashMap<String, bool> = HashMap::new();
 
@@ -229,25 +294,21 @@ impl ProcessTracer {
             }
 
             // Check required patterns
-            for pattern in &self.config.required_patterns {
-                if let Ok(re) = Regex::new(pattern) {
-                    if re.is_match(&execution.command) {
-                        required_found.insert(pattern.clone(), true);
-                    }
+            for (pattern, re) in &self.required {
+                if re.is_match(&execution.command) {
+                    required_found.insert(pattern.clone(), true);
                 }
             }
 
             // Check forbidden patterns
-            for pattern in &self.config.forbidden_patterns {
-                if let Ok(re) = Regex::new(pattern) {
-                    if re.is_match(&execution.command) {
-                        forbidden_used.insert(pattern.clone(), true);
-                        issues.push(format!(
-                            "Forbidden command pattern '{}' matched: '{}'",
-                            pattern,
-                            truncate_command(&execution.command, 50)
-                        ));
-                    }
+            for (pattern, re) in &self.forbidden {
+                if re.is_match(&execution.command) {
+                    forbidden_used.insert(pattern.clone(), true);
+                    issues.push(format!(
+                        "Forbidden command pattern '{}' matched: '{}'",
+                        pattern,
+                        truncate_command(&execution.command, 50)
+                    ));
                 }
             }
         }
@@ -275,6 +336,28 @@ impl ProcessTracer {
     }
 }
 
+/// Forbidden command detected while recording a live command feed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProcessValidationIssue {
+    /// The forbidden pattern that matched
+    pub pattern: String,
+    /// The offending command
+    pub command: String,
+    /// Position of the command among recorded executions
+    pub command_index: usize,
+}
+
+impl ProcessValidationIssue {
+    /// Human-readable description, matching the wording used by `validate()`.
+    pub fn message(&self) -> String {
+        format!(
+            "Forbidden command pattern '{}' matched: '{}'",
+            self.pattern,
+            truncate_command(&self.command, 50)
+        )
+    }
+}
+
 /// Result of process validation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProcessValidationResult {
@@ -423,6 +506,30 @@ mod tests {
         assert!(*result.forbidden_used.get(r"rm\s+-rf").unwrap());
     }
 
+    #[test]
+    fn test_record_and_check_detects_forbidden_command_early() {
+        let config = ProcessValidationConfig::new().with_forbidden_pattern(r"curl.*answer");
+
+        let mut tracer = ProcessTracer::new(config);
+        assert!(tracer
+            .record_and_check(create_test_execution("ls -la", 0, 0.1))
+            .is_none());
+
+        let issue = tracer
+            .record_and_check(create_test_execution(
+                "curl http://example.com/answer",
+                0,
+                0.2,
+            ))
+            .expect("forbidden command should be reported immediately");
+
+        assert_eq!(issue.pattern, r"curl.*answer");
+        assert_eq!(issue.command_index, 1);
+        assert!(issue.message().contains("Forbidden command pattern"));
+        assert_eq!(tracer.execution_count(), 2);
+        assert!(!tracer.validate().valid);
+    }
+
     #[test]
     fn test_min_commands_validation() {
         let config = ProcessValidationConfig::new().with_min_commands(3);
@@ -464,6 +571,28 @@ mod tests {
         assert!(result.issues.iter().any(|i| i.contains("exceeds limit")));
     }
 
+    #[test]
+    fn test_invalid_pattern_reported() {
+        let config = ProcessValidationConfig::new()
+            .with_forbidden_pattern(r"rm -rf (")
+            .with_forbidden_pattern(r"curl");
+
+        let mut tracer = ProcessTracer::new(config);
+        assert_eq!(tracer.invalid_patterns().len(), 1);
+        assert!(tracer.invalid_patterns()[0].contains("rm -rf ("));
+
+        // Valid patterns still apply.
+        let issue = tracer.record_and_check(create_test_execution("curl x", 0, 0.1));
+        assert_eq!(issue.map(|i| i.pattern), Some("curl".to_string()));
+
+        let result = tracer.validate();
+        assert!(!result.valid);
+        assert!(result
+            .issues
+            .iter()
+            .any(|i| i.starts_with("Invalid command pattern 'rm -rf ('")));
+    }
+
     #[test]
     fn test_score_calculation() {
         let config = ProcessValidationConfig::new()
@@ -7,10 +7,13 @@ use crate::agents::{
     AntiMemorizationConfig, DifficultyScoring, DockerValidatorAgent, DockerValidatorConfig,
     HiddenSolution, SyntheticTask, TaskMetadata, VerificationSpec,
 };
+use crate::anti_hardcoding::{detect_contamination, CanaryConfig};
 use crate::difficulty::DifficultyLevel;
+use crate::error::ImportError;
+use crate::export::{TaskImporter, TerminalBenchExporter};
 use crate::llm::{LiteLlmClient, OpenRouterProvider};
 use crate::swe::orchestrator::DifficultyTargets;
-use crate::swe::{SweOrchestrator, SweOrchestratorConfig};
+use crate::swe::{SweOrchestrator, SweOrchestratorConfig, CANARY_META_KEY};
 use clap::Parser;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
@@ -66,6 +69,42 @@ pub enum Commands {
     /// Update swe-forge to the latest version from GitHub Releases.
     #[command(name = "self-update", alias = "update")]
     SelfUpdate(SelfUpdateArgs),
+
+    /// Print version, build commit and detected tool versions.
+    Version(VersionArgs),
+
+    /// Check whether any task canaries of an exported dataset appear in a text corpus.
+    #[command(name = "scan-contamination")]
+    ScanContamination(ScanContaminationArgs),
+}
+
+/// Arguments for the scan-contamination command.
+#[derive(Parser, Debug)]
+pub struct ScanContaminationArgs {
+    /// Dataset directory containing one task directory with `workspace.yaml` per task.
+    #[arg(long)]
+    pub dataset: String,
+
+    /// Text corpus to scan (e.g. suspected training data).
+    #[arg(long)]
+    pub corpus: String,
+
+    /// Pipeline root seed, used to regenerate canaries for SWE tasks exported
+    /// without a recorded `canary_id`.
+    #[arg(long)]
+    pub seed: Option<u64>,
+
+    /// Print the report as JSON.
+    #[arg(long, default_value_t = false)]
+    pub json: bool,
+}
+
+/// Arguments for the version command.
+#[derive(Parser, Debug)]
+pub struct VersionArgs {
+    /// Print machine-readable JSON instead of text.
+    #[arg(long, default_value_t = false)]
+    pub json: bool,
 }
 
 /// Arguments for the self-update command.
@@ -88,7 +127,7 @@ pub struct SweArgs {
 #[derive(clap::Subcommand, Debug)]
 pub enum SweSubcommand {
     /// Mine real PRs and export SWE DataForge-style tasks.
-    Mine(SweMineArgs),
+    Mine(Box<SweMineArgs>),
 
     /// Validate mined SWE tasks with quality scoring and optional Docker probes.
     Validate(SweValidateArgs),
@@ -203,6 +242,18 @@ pub struct SweMineArgs {
     #[arg(long)]
     pub backlog_multiplier: Option<usize>,
 
+    /// Write every pipeline event as timestamped JSONL to this file.
+    #[arg(long)]
+    pub event_log: Option<String>,
+
+    /// Save mining progress to this checkpoint file and resume from it if it exists.
+    #[arg(long)]
+    pub checkpoint: Option<String>,
+
+    /// Root seed for randomized steps (event shuffling); random and logged if unset.
+    #[arg(long)]
+    pub seed: Option<u64>,
+
     /// Output JSON summary.
     #[arg(short = 'j', long)]
     pub json: bool,
@@ -295,6 +346,11 @@ pub struct SweExportArgs {
     #[arg(short = 'o', long, default_value = "./exported-swe")]
     pub output: String,
 
+    /// Write terminal-bench task directories (task.yaml, prompt.md, solution.sh)
+    /// instead of copying the workspace artifacts.
+    #[arg(long)]
+    pub terminal_bench: bool,
+
     /// Return export summary as JSON.
     #[arg(short = 'j', long)]
     pub json: bool,
@@ -421,6 +477,22 @@ pub struct GenerateArgs {
     /// Disable Docker validation (useful in CI without Docker).
     #[arg(long, conflicts_with = "validate_docker")]
     pub no_docker: bool,
+
+    /// Write every pipeline event as timestamped JSONL to this file.
+    #[arg(long)]
+    pub event_log: Option<String>,
+
+    /// Save mining progress to this checkpoint file and resume from it if it exists.
+    #[arg(long)]
+    pub checkpoint: Option<String>,
+
+    /// Root seed for randomized steps (event shuffling); random and logged if unset.
+    #[arg(long)]
+    pub seed: Option<u64>,
+
+    /// Also export accepted tasks as terminal-bench task directories here.
+    #[arg(long)]
+    pub terminal_bench_dir: Option<String>,
 }
 
 /// Default maximum steps for the evaluation agent.
@@ -457,12 +529,20 @@ pub struct EvaluateArgs {
     pub timeout: u64,
 
     /// Output file for results (JSON format).
+    ///
+    /// In `--json` mode this file is JSONL: one `TaskEvaluationResult` per line,
+    /// appended as soon as each task completes so a crash does not lose finished
+    /// work. The run summary is written next to it as `<output>.summary.json`.
     #[arg(short = 'o', long)]
     pub output: Option<String>,
 
     /// Output JSON to stdout instead of interactive progress.
     #[arg(short = 'j', long)]
     pub json: bool,
+
+    /// Resume a previous `--json` run, skipping tasks already present in the output file.
+    #[arg(long, requires_all = ["output", "json"])]
+    pub resume: bool,
 }
 
 /// Parse CLI arguments and return the Cli struct.
@@ -497,6 +577,12 @@ pub async fn run_with_cli(cli: Cli) -> anyhow::Result<()> {
         Commands::SelfUpdate(args) => {
             crate::cli::self_update::run_self_update(args.force).await?;
         }
+        Commands::Version(args) => {
+            crate::cli::version::run_version(args.json, DEFAULT_MODEL).await?;
+        }
+        Commands::ScanContamination(args) => {
+            run_scan_contamination_command(args)?;
+        }
     }
     Ok(())
 }
@@ -507,7 +593,7 @@ pub async fn run_with_cli(cli: Cli) -> anyhow::Result<()> {
 
 async fn run_swe_command(args: SweArgs) -> anyhow::Result<()> {
     match args.command {
-        SweSubcommand::Mine(args) => run_swe_mine_command(args).await,
+        SweSubcommand::Mine(args) => run_swe_mine_command(*args).await,
         SweSubcommand::Validate(args) => run_swe_validate_command(args).await,
         SweSubcommand::Export(args) => run_swe_export_command(args).await,
         SweSubcommand::Harness(args) => run_swe_harness_command(args).await,
@@ -867,6 +953,12 @@ async fn run_swe_export_command(args: SweExportArgs) -> anyhow::Result<()> {
         }
 
         let task = load_swe_workspace_task(&workspace_yaml)?;
+        if args.terminal_bench {
+            TerminalBenchExporter::new(destination_dir).export_swe(&task)?;
+            copied = copied.saturating_add(1);
+            continue;
+        }
+
         let task_output = destination_dir.join(&task.id);
         fs::create_dir_all(&task_output)?;
 
@@ -997,6 +1089,13 @@ async fn run_swe_mine_command(args: SweMineArgs) -> anyhow::Result<()> {
         concurrency_deep: args.concurrency_deep,
         concurrency_preclassify: args.concurrency_preclassify,
         backlog_multiplier: args.backlog_multiplier,
+        event_log: args.event_log.clone(),
+        checkpoint: args.checkpoint.clone(),
+        archive_file: None,
+        seed: args.seed,
+        clock: crate::utils::system_clock(),
+        progress_interval: crate::swe::orchestrator::DEFAULT_PROGRESS_INTERVAL,
+        enricher: crate::swe::enricher::EnricherConfig::default(),
     };
 
     let orchestrator = SweOrchestrator::new(llm_client, config);
@@ -1123,6 +1222,13 @@ async fn run_swe_benchmark_command(args: SweBenchmarkArgs) -> anyhow::Result<()>
         concurrency_deep: args.concurrency_deep,
         concurrency_preclassify: args.concurrency_preclassify,
         backlog_multiplier: args.backlog_multiplier,
+        event_log: None,
+        checkpoint: None,
+        archive_file: None,
+        seed: None,
+        clock: crate::utils::system_clock(),
+        progress_interval: crate::swe::orchestrator::DEFAULT_PROGRESS_INTERVAL,
+        enricher: crate::swe::enricher::EnricherConfig::default(),
     };
 
     let orchestrator = SweOrchestrator::new(llm_client, config);
@@ -1202,6 +1308,157 @@ async fn run_swe_load_command(args: SweLoadArgs) -> anyhow::Result<()> {
     Ok(())
 }
 
+// ============================================================================
+// Contamination Scan Implementation
+// ============================================================================
+
+/// A task whose canary was (partially) found in the scanned corpus.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ContaminationFinding {
+    task_id: String,
+    canary_id: String,
+    contaminated: bool,
+    partial_match: bool,
+    confidence: f64,
+}
+
+/// Summary of a dataset contamination scan.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct ContaminationScanReport {
+    tasks_scanned: usize,
+    tasks_without_canary: Vec<String>,
+    findings: Vec<ContaminationFinding>,
+}
+
+impl ContaminationScanReport {
+    fn contaminated_task_ids(&self) -> Vec<&str> {
+        self.findings
+            .iter()
+            .filter(|f| f.contaminated)
+            .map(|f| f.task_id.as_str())
+            .collect()
+    }
+}
+
+fn run_scan_contamination_command(args: ScanContaminationArgs) -> anyhow::Result<()> {
+    let corpus = fs::read_to_string(&args.corpus)
+        .map_err(|e| anyhow::anyhow!("Failed to read corpus {}: {}", args.corpus, e))?;
+    let report = scan_dataset_contamination(Path::new(&args.dataset), &corpus, args.seed)?;
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    println!(
+        "Scanned {} tasks from {}",
+        report.tasks_scanned, args.dataset
+    );
+    if !report.tasks_without_canary.is_empty() {
+        println!(
+            "  {} tasks have no canary (embed a canary token, record `{}` in workspace meta, or pass --seed)",
+            report.tasks_without_canary.len(),
+            CANARY_META_KEY
+        );
+    }
+    for finding in &report.findings {
+        let label = if finding.contaminated {
+            "CONTAMINATED"
+        } else {
+            "partial match"
+        };
+        println!(
+            "  {}: {} (confidence {:.2})",
+            finding.task_id, label, finding.confidence
+        );
+    }
+    println!(
+        "Contaminated tasks: {}",
+        report.contaminated_task_ids().len()
+    );
+    Ok(())
+}
+
+fn scan_dataset_contamination(
+    dataset: &Path,
+    corpus: &str,
+    seed: Option<u64>,
+) -> anyhow::Result<ContaminationScanReport> {
+    let mut task_dirs = fs::read_dir(dataset)
+        .map_err(|e| anyhow::anyhow!("Failed to read dataset {}: {}", dataset.display(), e))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.join("workspace.yaml").is_file() || path.join("task.yaml").is_file())
+        .collect::<Vec<_>>();
+    task_dirs.sort();
+
+    let mut report = ContaminationScanReport::default();
+    for task_dir in task_dirs {
+        let (task_id, recorded) = match load_task_canary(&task_dir) {
+            Ok(found) => found,
+            Err(e) => {
+                warn!(
+                    path = %task_dir.display(),
+                    error = %e,
+                    "Failed to load task, skipping"
+                );
+                continue;
+            }
+        };
+        report.tasks_scanned += 1;
+
+        let canary = match (recorded, seed) {
+            (Some(canary), _) => canary,
+            (None, Some(seed)) => CanaryConfig::generate(&task_id, seed),
+            (None, None) => {
+                report.tasks_without_canary.push(task_id);
+                continue;
+            }
+        };
+
+        let result = detect_contamination(corpus, &canary);
+        if result.contaminated || result.partial_match {
+            report.findings.push(ContaminationFinding {
+                task_id,
+                canary_id: canary.canary_id,
+                contaminated: result.contaminated,
+                partial_match: result.partial_match,
+                confidence: result.confidence,
+            });
+        }
+    }
+
+    Ok(report)
+}
+
+/// Task id and recorded canary of an exported task directory.
+///
+/// SWE workspaces record the canary under `CANARY_META_KEY`; synthetic and
+/// terminal-bench tasks (`task.yaml`) carry the canary token embedded at
+/// generation time.
+fn load_task_canary(task_dir: &Path) -> anyhow::Result<(String, Option<CanaryConfig>)> {
+    let workspace_yaml = task_dir.join("workspace.yaml");
+    if workspace_yaml.is_file() {
+        let task = load_swe_workspace_task(&workspace_yaml)?;
+        let canary = task
+            .meta
+            .get(CANARY_META_KEY)
+            .map(|canary_id| CanaryConfig::from_id(canary_id.clone()));
+        return Ok((task.id, canary));
+    }
+
+    let task = load_synthetic_task(&task_dir.join("task.yaml"))?;
+    let token = task.anti_memorization.canary_token.trim();
+    let canary = (!token.is_empty()).then(|| canary_from_token(token));
+    Ok((task.id, canary))
+}
+
+/// Rebuild a canary from an embedded token such as `DATAFORGE_CANARY_CANARY-<uuid>`,
+/// dropping the generator prefix so partial UUID matches are still detected.
+fn canary_from_token(token: &str) -> CanaryConfig {
+    let canary_id = token.find("CANARY-").map_or(token, |start| &token[start..]);
+    CanaryConfig::from_id(canary_id)
+}
+
 fn load_swe_workspace_task(path: &Path) -> anyhow::Result<crate::swe::SweTask> {
     let content = fs::read_to_string(path)?;
     serde_yaml::from_str(&content).map_err(|e| {
@@ -1385,6 +1642,10 @@ async fn run_generate_command(args: GenerateArgs) -> anyhow::Result<()> {
         max_tasks: args.count.max(1) as usize,
         once: args.count <= 1,
         validate_docker: args.validate_docker && !args.no_docker,
+        event_log: args.event_log.clone(),
+        checkpoint: args.checkpoint.clone(),
+        archive_file: None,
+        seed: args.seed,
         ..SweOrchestratorConfig::default()
     };
 
@@ -1392,6 +1653,14 @@ async fn run_generate_command(args: GenerateArgs) -> anyhow::Result<()> {
     let start = std::time::Instant::now();
     let result = orchestrator.mine().await?;
 
+    if let Some(ref dir) = args.terminal_bench_dir {
+        let exporter = TerminalBenchExporter::new(dir);
+        for task in &result.tasks {
+            exporter.export_swe(task)?;
+        }
+        info!(count = result.tasks.len(), output = %dir, "Exported terminal-bench tasks");
+    }
+
     let requested_category = args.category.clone().map(|c| c.to_lowercase());
 
     let generated_tasks: Vec<GeneratedTaskOutput> = result
@@ -1681,9 +1950,7 @@ fn load_tasks_from_directory(dir: &Path) -> anyhow::Result<Vec<LoadedTask>> {
 
 /// Load a single task from a task.yaml file.
 fn load_task_from_yaml(path: &Path) -> anyhow::Result<LoadedTask> {
-    let content = fs::read_to_string(path)?;
-    let task: SyntheticTask = serde_yaml::from_str(&content)
-        .map_err(|e| anyhow::anyhow!("Failed to parse task YAML: {}", e))?;
+    let task = load_synthetic_task(path)?;
 
     Ok(LoadedTask {
         task_id: task.id,
@@ -1694,6 +1961,30 @@ fn load_task_from_yaml(path: &Path) -> anyhow::Result<LoadedTask> {
     })
 }
 
+/// Parse a task.yaml file into a `SyntheticTask`.
+///
+/// Accepts a serialized `SyntheticTask` or a terminal-bench task directory as
+/// written by `TerminalBenchExporter`.
+fn load_synthetic_task(path: &Path) -> anyhow::Result<SyntheticTask> {
+    let content = fs::read_to_string(path)?;
+    match serde_yaml::from_str::<SyntheticTask>(&content) {
+        Ok(task) => Ok(task),
+        Err(yaml_err) => {
+            let dir = path.parent().unwrap_or_else(|| Path::new("."));
+            TaskImporter::new().import_task(dir).map_err(|import_err| {
+                let location = yaml_err.location();
+                ImportError::Parse {
+                    path: path.display().to_string(),
+                    message: format!("{yaml_err} (terminal-bench import: {import_err})"),
+                    line: location.as_ref().map(|l| l.line()),
+                    column: location.as_ref().map(|l| l.column()),
+                }
+                .into()
+            })
+        }
+    }
+}
+
 /// Evaluate a single task using the LLM agent.
 async fn evaluate_single_task(
     llm_client: Arc<dyn crate::llm::LlmProvider>,
@@ -1808,22 +2099,136 @@ end your response with "TASK COMPLETE" followed by your final answer."#;
     }
 }
 
-/// Run evaluation in JSON mode (outputs JSON to stdout).
-async fn run_json_evaluation(
+/// Read a JSONL results file, returning its raw content (empty if missing).
+fn read_evaluation_results_file(path: &Path) -> anyhow::Result<String> {
+    if !path.exists() {
+        return Ok(String::new());
+    }
+    fs::read_to_string(path).map_err(|e| anyhow::anyhow!("Failed to read results file: {}", e))
+}
+
+/// Parse previously recorded evaluation results from JSONL content read from `path`.
+///
+/// Malformed lines (e.g. a partially written line from a crashed run) are
+/// skipped with a warning so the corresponding task is evaluated again.
+fn parse_evaluation_results(path: &Path, content: &str) -> Vec<TaskEvaluationResult> {
+    let mut results = Vec::new();
+    for (idx, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<TaskEvaluationResult>(line) {
+            Ok(result) => results.push(result),
+            Err(e) => {
+                warn!(
+                    path = %path.display(),
+                    line = idx + 1,
+                    error = %e,
+                    "Skipping malformed evaluation result line"
+                );
+            }
+        }
+    }
+
+    results
+}
+
+/// Append a single evaluation result to a JSONL results file.
+fn append_evaluation_result(path: &Path, result: &TaskEvaluationResult) -> anyhow::Result<()> {
+    use std::io::Write;
+
+    let line = serde_json::to_string(result)
+        .map_err(|e| anyhow::anyhow!("Failed to serialize task result: {}", e))?;
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| anyhow::anyhow!("Failed to open results file: {}", e))?;
+    writeln!(file, "{}", line).map_err(|e| anyhow::anyhow!("Failed to write task result: {}", e))
+}
+
+/// Evaluate tasks one by one, persisting each result as soon as it completes.
+///
+/// When `args.resume` is set, results already present in the output file are
+/// kept and their tasks skipped. Returns the results for `tasks`, including
+/// those recovered from a previous run.
+async fn evaluate_tasks_incrementally(
     llm_client: Arc<dyn crate::llm::LlmProvider>,
     args: &EvaluateArgs,
-    tasks: Vec<LoadedTask>,
-) -> anyhow::Result<()> {
-    let start_time = std::time::Instant::now();
-    let total_tasks = tasks.len();
-    let mut task_results: Vec<TaskEvaluationResult> = Vec::new();
+    tasks: &[LoadedTask],
+) -> anyhow::Result<Vec<TaskEvaluationResult>> {
+    let output_path = args.output.as_deref().map(Path::new);
+
+    let mut task_results = match output_path {
+        Some(path) if args.resume => {
+            let content = read_evaluation_results_file(path)?;
+            let existing = parse_evaluation_results(path, &content);
+            if existing.is_empty() && !content.trim().is_empty() {
+                return Err(anyhow::anyhow!(
+                    "Results file {} has no valid evaluation results; refusing to resume into it",
+                    path.display()
+                ));
+            }
+            // Start new appends on a fresh line after a truncated trailing line.
+            if !content.is_empty() && !content.ends_with('\n') {
+                use std::io::Write;
+                let mut file = fs::OpenOptions::new()
+                    .append(true)
+                    .open(path)
+                    .map_err(|e| anyhow::anyhow!("Failed to open results file: {}", e))?;
+                writeln!(file)
+                    .map_err(|e| anyhow::anyhow!("Failed to write results file: {}", e))?;
+            }
+            info!(
+                path = %path.display(),
+                completed = existing.len(),
+                "Resuming evaluation from existing results"
+            );
+            existing
+        }
+        Some(path) => {
+            fs::write(path, "")
+                .map_err(|e| anyhow::anyhow!("Failed to create results file: {}", e))?;
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+
+    // Results for tasks outside the current task set stay in the file but are
+    // not part of this run's summary.
+    let task_ids: HashSet<&str> = tasks.iter().map(|t| t.task_id.as_str()).collect();
+    task_results.retain(|r| task_ids.contains(r.task_id.as_str()));
+    let completed: HashSet<String> = task_results.iter().map(|r| r.task_id.clone()).collect();
+
+    for task in tasks {
+        if completed.contains(&task.task_id) {
+            info!(task_id = %task.task_id, "Skipping already evaluated task");
+            continue;
+        }
 
-    for task in &tasks {
         let result =
             evaluate_single_task(llm_client.clone(), task, args.max_steps, args.timeout).await;
+        if let Some(path) = output_path {
+            append_evaluation_result(path, &result)?;
+        }
         task_results.push(result);
     }
 
+    Ok(task_results)
+}
+
+/// Run evaluation in JSON mode (outputs JSON to stdout).
+async fn run_json_evaluation(
+    llm_client: Arc<dyn crate::llm::LlmProvider>,
+    args: &EvaluateArgs,
+    tasks: Vec<LoadedTask>,
+) -> anyhow::Result<()> {
+    let start_time = std::time::Instant::now();
+    let task_results = evaluate_tasks_incrementally(llm_client, args, &tasks).await?;
+
+    // Summarize over the complete result set, including resumed results.
+    let total_tasks = task_results.len();
     let total_duration_ms = start_time.elapsed().as_millis() as u64;
     let successful_tasks = task_results.iter().filter(|r| r.success).count();
     let success_rate = if total_tasks > 0 {
@@ -1858,11 +2263,15 @@ async fn run_json_evaluation(
     let json_output = serde_json::to_string_pretty(&output)
         .map_err(|e| anyhow::anyhow!("Failed to serialize JSON output: {}", e))?;
 
-    // Write to file if specified
     if let Some(output_path) = &args.output {
-        fs::write(output_path, &json_output)
-            .map_err(|e| anyhow::anyhow!("Failed to write output file: {}", e))?;
-        info!(path = %output_path, "Results written to file");
+        let summary_path = evaluation_summary_path(output_path);
+        fs::write(&summary_path, &json_output)
+            .map_err(|e| anyhow::anyhow!("Failed to write summary file: {}", e))?;
+        info!(
+            path = %output_path,
+            summary = %summary_path.display(),
+            "Results written to file"
+        );
     }
 
     println!("{}", json_output);
@@ -1870,6 +2279,11 @@ async fn run_json_evaluation(
     Ok(())
 }
 
+/// Path of the run summary written alongside a JSONL results file.
+fn evaluation_summary_path(output: &str) -> std::path::PathBuf {
+    std::path::PathBuf::from(format!("{}.summary.json", output))
+}
+
 /// Run evaluation in interactive mode with progress output.
 async fn run_interactive_evaluation(
     llm_client: Arc<dyn crate::llm::LlmProvider>,
@@ -2090,6 +2504,129 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_version_command_json_flag() {
+        let cli = Cli::try_parse_from(["swe_forge", "version", "--json"]).expect("should parse");
+        match cli.command {
+            Commands::Version(args) => assert!(args.json),
+            _ => panic!("Expected version command"),
+        }
+    }
+
+    #[test]
+    fn test_scan_contamination_detects_leaked_canary() {
+        let dataset = tempfile::tempdir().unwrap();
+        let leaked = CanaryConfig::generate("acme-widgets-1", 7);
+        let clean = CanaryConfig::generate("acme-widgets-2", 7);
+
+        for (task_id, canary) in [("acme-widgets-1", &leaked), ("acme-widgets-2", &clean)] {
+            let mut task = crate::swe::SweTask::new(task_id, "acme/widgets");
+            task.meta
+                .insert(CANARY_META_KEY.to_string(), canary.canary_id.clone());
+            let task_dir = dataset.path().join(task_id);
+            fs::create_dir_all(&task_dir).unwrap();
+            fs::write(
+                task_dir.join("workspace.yaml"),
+                serde_yaml::to_string(&task).unwrap(),
+            )
+            .unwrap();
+        }
+        let untagged = crate::swe::SweTask::new("acme-widgets-3", "acme/widgets");
+        let untagged_dir = dataset.path().join("acme-widgets-3");
+        fs::create_dir_all(&untagged_dir).unwrap();
+        fs::write(
+            untagged_dir.join("workspace.yaml"),
+            serde_yaml::to_string(&untagged).unwrap(),
+        )
+        .unwrap();
+
+        let corpus = format!("scraped page\n{}\nmore text", leaked.canary_id);
+        let report = scan_dataset_contamination(dataset.path(), &corpus, None).unwrap();
+
+        assert_eq!(report.tasks_scanned, 3);
+        assert_eq!(report.contaminated_task_ids(), vec!["acme-widgets-1"]);
+        assert_eq!(
+            report.tasks_without_canary,
+            vec!["acme-widgets-3".to_string()]
+        );
+
+        let reseeded = scan_dataset_contamination(dataset.path(), &corpus, Some(7)).unwrap();
+        assert!(reseeded.tasks_without_canary.is_empty());
+    }
+
+    #[test]
+    fn test_scan_contamination_reads_embedded_canary_tokens() {
+        let dataset = tempfile::tempdir().unwrap();
+        let leaked = CanaryConfig::generate("log-task", 11);
+        let mut task = SyntheticTask::new(
+            "Count the ERROR lines in /var/log/app.log.",
+            HiddenSolution::new("grep").with_reference_commands(["grep -c ERROR /var/log/app.log"]),
+            VerificationSpec::new(),
+            DifficultyScoring::new(DifficultyLevel::Easy),
+            TaskMetadata::new("log-analysis", "idea-1"),
+        )
+        .with_anti_memorization(AntiMemorizationConfig::new(format!(
+            "DATAFORGE_CANARY_{}",
+            leaked.canary_id
+        )));
+        task.id = "log-task".to_string();
+        TerminalBenchExporter::new(dataset.path())
+            .export_synthetic(&task)
+            .unwrap();
+
+        // A malformed task directory is skipped instead of aborting the scan.
+        let broken = dataset.path().join("broken");
+        fs::create_dir_all(&broken).unwrap();
+        fs::write(broken.join("workspace.yaml"), "id: [unclosed").unwrap();
+
+        let corpus = format!("leaked: {}", leaked.canary_id);
+        let report = scan_dataset_contamination(dataset.path(), &corpus, None).unwrap();
+
+        assert_eq!(report.tasks_scanned, 1);
+        assert_eq!(report.contaminated_task_ids(), vec!["log-task"]);
+    }
+
+    #[test]
+    fn test_load_tasks_from_terminal_bench_export() {
+        let root = tempfile::tempdir().unwrap();
+        let mut task = crate::swe::SweTask::new("acme-widgets-7", "acme/widgets");
+        task.language = "python".to_string();
+        task.prompt = "Fix the pagination bug.".to_string();
+        task.patch = "diff --git a/app.py b/app.py\n".to_string();
+        task.fail_to_pass = vec!["pytest tests/test_pages.py".to_string()];
+        task.meta
+            .insert("difficulty".to_string(), "hard".to_string());
+        TerminalBenchExporter::new(root.path())
+            .export_swe(&task)
+            .unwrap();
+
+        let loaded = load_tasks_from_directory(root.path()).unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].task_id, "acme-widgets-7");
+        assert_eq!(loaded[0].category, "python");
+        assert_eq!(loaded[0].difficulty, "Hard");
+        assert_eq!(loaded[0].problem_statement, "Fix the pagination bug.");
+    }
+
+    #[test]
+    fn test_load_synthetic_task_reports_parse_location() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("task.yaml");
+        fs::write(&path, "id: broken\nproblem_statement: [unclosed\n").unwrap();
+
+        let err = load_synthetic_task(&path).unwrap_err();
+        match err.downcast_ref::<ImportError>() {
+            Some(ImportError::Parse { line, column, .. }) => {
+                assert_eq!(*line, Some(2));
+                assert!(column.is_some());
+            }
+            other => panic!("expected Parse error, got {other:?}"),
+        }
+        let message = err.to_string();
+        assert!(message.contains("task.yaml' at line 2"), "{message}");
+        assert!(message.contains("terminal-bench import:"), "{message}");
+    }
+
     #[test]
     fn test_generation_output_serialization() {
         let output = GenerationOutput {
@@ -2130,6 +2667,7 @@ mod tests {
                 assert_eq!(args.timeout, DEFAULT_EVAL_TIMEOUT_SECS);
                 assert!(args.output.is_none());
                 assert!(!args.json);
+                assert!(!args.resume);
             }
             _ => panic!("Expected Evaluate command"),
         }
@@ -2318,4 +2856,156 @@ mod tests {
         assert!(metrics.medium_avg_duration_ms.is_none());
         assert!(metrics.hard_avg_duration_ms.is_none());
     }
+
+    #[test]
+    fn test_evaluate_resume_requires_output_and_json() {
+        let args = vec!["swe_forge", "evaluate", "-t", "/tmp/tasks", "--resume"];
+        assert!(Cli::try_parse_from(args).is_err());
+
+        let args = vec![
+            "swe_forge",
+            "evaluate",
+            "-t",
+            "/tmp/tasks",
+            "-j",
+            "-o",
+            "./results.jsonl",
+            "--resume",
+        ];
+        let cli = Cli::try_parse_from(args).expect("should parse");
+        match cli.command {
+            Commands::Evaluate(args) => assert!(args.resume),
+            _ => panic!("Expected Evaluate command"),
+        }
+    }
+
+    struct CountingLlmProvider {
+        calls: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::llm::LlmProvider for CountingLlmProvider {
+        async fn generate(
+            &self,
+            _request: crate::llm::GenerationRequest,
+        ) -> Result<crate::llm::GenerationResponse, crate::error::LlmError> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(crate::llm::GenerationResponse {
+                id: "test-id".to_string(),
+                model: "test-model".to_string(),
+                choices: vec![crate::llm::Choice {
+                    index: 0,
+                    message: crate::llm::Message::assistant("Done. TASK COMPLETE"),
+                    finish_reason: "stop".to_string(),
+                }],
+                usage: crate::llm::Usage {
+                    prompt_tokens: 10,
+                    completion_tokens: 5,
+                    total_tokens: 15,
+                },
+            })
+        }
+    }
+
+    fn loaded_task(id: &str) -> LoadedTask {
+        LoadedTask {
+            task_id: id.to_string(),
+            category: "debugging".to_string(),
+            difficulty: "Easy".to_string(),
+            problem_statement: "Fix the bug".to_string(),
+            success_criteria: vec!["Tests pass".to_string()],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resume_evaluation_skips_completed_tasks() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let output = dir.path().join("results.jsonl");
+
+        // Simulate a crashed run: one complete result plus a truncated line.
+        let previous = TaskEvaluationResult {
+            task_id: "task-001".to_string(),
+            category: "debugging".to_string(),
+            difficulty: "Easy".to_string(),
+            success: true,
+            steps_taken: 1,
+            duration_ms: 100,
+            error: None,
+            agent_output: None,
+        };
+        append_evaluation_result(&output, &previous).expect("append");
+        {
+            use std::io::Write;
+            let mut file = fs::OpenOptions::new()
+                .append(true)
+                .open(&output)
+                .expect("open");
+            write!(file, "{{\"task_id\": \"task-00").expect("write partial");
+        }
+
+        let provider = Arc::new(CountingLlmProvider {
+            calls: std::sync::atomic::AtomicUsize::new(0),
+        });
+        let args = EvaluateArgs {
+            tasks_dir: dir.path().display().to_string(),
+            model: "test-model".to_string(),
+            api_key: None,
+            max_steps: 5,
+            timeout: 60,
+            output: Some(output.display().to_string()),
+            json: true,
+            resume: true,
+        };
+        let tasks = vec![loaded_task("task-001"), loaded_task("task-002")];
+
+        let results = evaluate_tasks_incrementally(provider.clone(), &args, &tasks)
+            .await
+            .expect("evaluation should succeed");
+
+        assert_eq!(provider.calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert_eq!(results.len(), 2);
+
+        let persisted =
+            parse_evaluation_results(&output, &read_evaluation_results_file(&output).unwrap());
+        let ids: Vec<&str> = persisted.iter().map(|r| r.task_id.as_str()).collect();
+        assert_eq!(ids, vec!["task-001", "task-002"]);
+        assert_eq!(previous.duration_ms, persisted[0].duration_ms);
+
+        // Resuming with a smaller task set only reports that set.
+        let results = evaluate_tasks_incrementally(provider.clone(), &args, &tasks[1..])
+            .await
+            .expect("evaluation should succeed");
+        assert_eq!(provider.calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+        let ids: Vec<&str> = results.iter().map(|r| r.task_id.as_str()).collect();
+        assert_eq!(ids, vec!["task-002"]);
+    }
+
+    #[tokio::test]
+    async fn test_resume_refuses_unreadable_results_file() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let output = dir.path().join("results.json");
+        let summary = "{\n  \"status\": \"success\"\n}\n";
+        fs::write(&output, summary).expect("write");
+
+        let provider = Arc::new(CountingLlmProvider {
+            calls: std::sync::atomic::AtomicUsize::new(0),
+        });
+        let args = EvaluateArgs {
+            tasks_dir: dir.path().display().to_string(),
+            model: "test-model".to_string(),
+            api_key: None,
+            max_steps: 5,
+            timeout: 60,
+            output: Some(output.display().to_string()),
+            json: true,
+            resume: true,
+        };
+
+        let err = evaluate_tasks_incrementally(provider.clone(), &args, &[loaded_task("task-001")])
+            .await
+            .expect_err("resume should refuse");
+        assert!(err.to_string().contains("no valid evaluation results"));
+        assert_eq!(provider.calls.load(std::sync::atomic::Ordering::SeqCst), 0);
+        assert_eq!(fs::read_to_string(&output).expect("read"), summary);
+    }
 }
@@ -5,5 +5,6 @@
 
 mod commands;
 pub mod self_update;
+pub mod version;
 
 pub use commands::{parse_cli, run, run_with_cli};
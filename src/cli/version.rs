@@ -0,0 +1,123 @@
+//! Version reporting for the swe-forge binary.
+//!
+//! `swe-forge version` prints the crate version, the git commit the binary was
+//! built from, the default model, and the availability of external tools used
+//! by the pipeline. `--json` emits the same data in a machine-readable form so
+//! it can be recorded alongside generated datasets.
+
+use serde::{Deserialize, Serialize};
+use tokio::process::Command;
+
+const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+const GIT_COMMIT: &str = env!("SWE_FORGE_GIT_COMMIT");
+
+/// Detected availability and version of an external tool.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComponentVersion {
+    /// Whether the tool could be executed.
+    pub available: bool,
+    /// Reported version string, when available.
+    pub version: Option<String>,
+}
+
+/// Version metadata describing this swe-forge build and its environment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VersionInfo {
+    /// Crate version (`CARGO_PKG_VERSION`).
+    pub version: String,
+    /// Git commit the binary was built from, or `unknown`.
+    pub git_commit: String,
+    /// Default LLM model used by generation commands.
+    pub default_model: String,
+    /// Docker engine availability.
+    pub docker: ComponentVersion,
+    /// SWE-agent CLI availability.
+    pub swe_agent: ComponentVersion,
+}
+
+impl VersionInfo {
+    /// Build-time fields only, without probing external tools.
+    pub fn build(default_model: &str) -> Self {
+        Self {
+            version: CURRENT_VERSION.to_string(),
+            git_commit: GIT_COMMIT.to_string(),
+            default_model: default_model.to_string(),
+            docker: ComponentVersion::default(),
+            swe_agent: ComponentVersion::default(),
+        }
+    }
+
+    /// Build-time fields plus detected docker and SWE-agent versions.
+    pub async fn detect(default_model: &str) -> Self {
+        let mut info = Self::build(default_model);
+        info.docker = match probe("docker", &["version", "--format", "{{.Server.Version}}"]).await {
+            Some(version) => ComponentVersion {
+                available: true,
+                version: Some(version),
+            },
+            None => probe_component("docker", &["--version"]).await,
+        };
+        info.swe_agent = probe_component("sweagent", &["--version"]).await;
+        info
+    }
+}
+
+/// Run `program args` and return its trimmed stdout on success.
+async fn probe(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().await.ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!stdout.is_empty()).then_some(stdout)
+}
+
+async fn probe_component(program: &str, args: &[&str]) -> ComponentVersion {
+    let version = probe(program, args).await;
+    ComponentVersion {
+        available: version.is_some(),
+        version,
+    }
+}
+
+/// Print version information, as JSON when `json` is set.
+pub async fn run_version(json: bool, default_model: &str) -> anyhow::Result<()> {
+    let info = VersionInfo::detect(default_model).await;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&info)?);
+        return Ok(());
+    }
+
+    let describe = |component: &ComponentVersion| match (&component.version, component.available) {
+        (Some(version), _) => version.clone(),
+        (None, true) => "available".to_string(),
+        (None, false) => "not found".to_string(),
+    };
+    println!("swe-forge {} ({})", info.version, info.git_commit);
+    println!("default model: {}", info.default_model);
+    println!("docker:        {}", describe(&info.docker));
+    println!("swe-agent:     {}", describe(&info.swe_agent));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_json_contains_crate_version() {
+        let info = VersionInfo::build("openai/gpt-5.2-codex:nitro");
+        let json: serde_json::Value = serde_json::to_value(&info).unwrap();
+
+        assert_eq!(json["version"], env!("CARGO_PKG_VERSION"));
+        assert_eq!(json["default_model"], "openai/gpt-5.2-codex:nitro");
+        assert!(!json["git_commit"].as_str().unwrap().is_empty());
+        assert_eq!(json["docker"]["available"], false);
+    }
+
+    #[tokio::test]
+    async fn test_probe_missing_program() {
+        let component = probe_component("swe-forge-definitely-missing-tool", &["--version"]).await;
+        assert_eq!(component, ComponentVersion::default());
+    }
+}
@@ -52,6 +52,23 @@ impl DifficultyLevel {
         }
     }
 
+    /// Returns the level whose target success rate is closest to `success_rate`.
+    pub fn from_success_rate(success_rate: f64) -> Self {
+        let rate = success_rate.clamp(0.0, 1.0);
+        [
+            DifficultyLevel::Easy,
+            DifficultyLevel::Medium,
+            DifficultyLevel::Hard,
+        ]
+        .into_iter()
+        .min_by(|a, b| {
+            let da = (a.target_success_rate() - rate).abs();
+            let db = (b.target_success_rate() - rate).abs();
+            da.total_cmp(&db)
+        })
+        .unwrap_or(DifficultyLevel::Medium)
+    }
+
     /// Returns the base points awarded for completing a task at this difficulty.
     pub fn base_points(&self) -> f64 {
         match self {
@@ -189,6 +206,52 @@ impl CalibrationResult {
     }
 }
 
+/// A task whose observed agent success rate contradicts its difficulty label.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DifficultyMislabel {
+    /// Position of the task in the input observations.
+    pub index: usize,
+    /// Difficulty level the task was labeled with.
+    pub labeled_level: DifficultyLevel,
+    /// Success rate observed during evaluation (0.0 to 1.0).
+    pub observed_success_rate: f64,
+    /// Target success rate of the labeled level.
+    pub target_success_rate: f64,
+    /// Level whose target success rate best matches the observation.
+    pub suggested_level: DifficultyLevel,
+}
+
+/// Finds tasks whose observed success rate deviates from their label's target.
+///
+/// # Arguments
+/// * `observations` - `(labeled level, observed success rate)` per task
+/// * `tolerance` - Maximum allowed absolute deviation from `target_success_rate()`
+///
+/// # Returns
+/// One `DifficultyMislabel` per task outside the tolerance, in input order
+pub fn find_mislabeled_difficulties(
+    observations: &[(DifficultyLevel, f64)],
+    tolerance: f64,
+) -> Vec<DifficultyMislabel> {
+    observations
+        .iter()
+        .enumerate()
+        .filter_map(|(index, &(level, observed))| {
+            let target = level.target_success_rate();
+            if (observed - target).abs() <= tolerance {
+                return None;
+            }
+            Some(DifficultyMislabel {
+                index,
+                labeled_level: level,
+                observed_success_rate: observed,
+                target_success_rate: target,
+                suggested_level: DifficultyLevel::from_success_rate(observed),
+            })
+        })
+        .collect()
+}
+
 /// Calculates a difficulty score based on task metrics.
 ///
 /// The score is a weighted combination of:
@@ -346,6 +409,38 @@ mod tests {
         assert!(result.difficulty_score >= 0.0 && result.difficulty_score <= 1.0);
     }
 
+    #[test]
+    fn test_easy_task_with_low_success_is_mislabeled() {
+        let observations = [
+            (DifficultyLevel::Easy, 0.20),
+            (DifficultyLevel::Easy, 0.85),
+            (DifficultyLevel::Hard, 0.45),
+        ];
+
+        let mislabeled = find_mislabeled_difficulties(&observations, 0.15);
+
+        assert_eq!(mislabeled.len(), 1);
+        assert_eq!(mislabeled[0].index, 0);
+        assert_eq!(mislabeled[0].labeled_level, DifficultyLevel::Easy);
+        assert_eq!(mislabeled[0].suggested_level, DifficultyLevel::Hard);
+    }
+
+    #[test]
+    fn test_level_from_success_rate() {
+        assert_eq!(
+            DifficultyLevel::from_success_rate(0.95),
+            DifficultyLevel::Easy
+        );
+        assert_eq!(
+            DifficultyLevel::from_success_rate(0.65),
+            DifficultyLevel::Medium
+        );
+        assert_eq!(
+            DifficultyLevel::from_success_rate(0.0),
+            DifficultyLevel::Hard
+        );
+    }
+
     #[test]
     fn test_resource_limits() {
         let easy_limits = DifficultyLevel::Easy.resource_limits();
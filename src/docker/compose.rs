@@ -4,9 +4,10 @@
 //! for multi-container task environments.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use super::dockerfile::DockerfileConfig;
+use crate::error::ComposeError;
 
 /// Build configuration for a compose service.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -151,6 +152,101 @@ impl Default for ComposeConfig {
     }
 }
 
+impl ComposeConfig {
+    /// Check the configuration for errors that would only surface when the
+    /// compose file is run.
+    ///
+    /// Verifies required fields, rejects duplicate service, container and
+    /// volume-mount names, and ensures every `depends_on` target, named volume
+    /// and network is declared. Services are checked in name order so the
+    /// reported error is deterministic.
+    pub fn validate(&self) -> Result<(), ComposeError> {
+        if self.version.trim().is_empty() {
+            return Err(ComposeError::MissingVersion);
+        }
+        if self.services.is_empty() {
+            return Err(ComposeError::NoServices);
+        }
+
+        let mut keys: Vec<&String> = self.services.keys().collect();
+        keys.sort();
+
+        let mut names = HashSet::new();
+        let mut container_names = HashSet::new();
+        for key in keys {
+            let service = &self.services[key];
+            let missing = |field: &str| ComposeError::MissingField {
+                service: key.clone(),
+                field: field.to_string(),
+            };
+
+            if service.name.trim().is_empty() {
+                return Err(missing("name"));
+            }
+            if service.container_name.trim().is_empty() {
+                return Err(missing("container_name"));
+            }
+            if service.image.is_some() == service.build.is_some() {
+                return Err(ComposeError::AmbiguousSource {
+                    service: key.clone(),
+                });
+            }
+            if !names.insert(service.name.as_str()) {
+                return Err(ComposeError::DuplicateService(service.name.clone()));
+            }
+            if !container_names.insert(service.container_name.as_str()) {
+                return Err(ComposeError::DuplicateContainerName(
+                    service.container_name.clone(),
+                ));
+            }
+
+            let mut targets = HashSet::new();
+            for mount in &service.volumes {
+                let mut parts = mount.split(':');
+                let source = parts.next().unwrap_or_default();
+                let target = parts.next().unwrap_or(source);
+                if !targets.insert(target) {
+                    return Err(ComposeError::DuplicateVolume {
+                        service: key.clone(),
+                        target: target.to_string(),
+                    });
+                }
+                let is_path = source.starts_with(['.', '/', '~', '$']);
+                if !is_path && target != source && !self.volumes.contains_key(source) {
+                    return Err(ComposeError::UndefinedVolume {
+                        service: key.clone(),
+                        volume: source.to_string(),
+                    });
+                }
+            }
+
+            if let Some(network) = service
+                .networks
+                .iter()
+                .find(|n| !self.networks.contains_key(*n))
+            {
+                return Err(ComposeError::UndefinedNetwork {
+                    service: key.clone(),
+                    network: network.clone(),
+                });
+            }
+
+            if let Some(dependency) = service
+                .depends_on
+                .iter()
+                .find(|d| !self.services.contains_key(*d))
+            {
+                return Err(ComposeError::UnknownDependency {
+                    service: key.clone(),
+                    dependency: dependency.clone(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
 /// Builder for generating docker-compose.yaml content.
 #[derive(Debug, Clone)]
 pub struct ComposeBuilder {
@@ -471,6 +567,16 @@ impl ComposeBuilder {
         self
     }
 
+    /// The configuration assembled so far.
+    pub fn config(&self) -> &ComposeConfig {
+        &self.config
+    }
+
+    /// Validate the assembled configuration; see [`ComposeConfig::validate`].
+    pub fn validate(&self) -> Result<(), ComposeError> {
+        self.config.validate()
+    }
+
     /// Build and return the docker-compose.yaml content as a YAML string.
     pub fn build(&self) -> String {
         let mut output = String::new();
@@ -611,6 +717,8 @@ mod tests {
             category: "file-operations".to_string(),
             difficulty: "easy".to_string(),
             packages: Vec::new(),
+            pre_install: Vec::new(),
+            post_install: Vec::new(),
             copy_paths: Vec::new(),
             env_vars: HashMap::new(),
             user: "user".to_string(),
@@ -635,6 +743,8 @@ mod tests {
             category: "data-science".to_string(),
             difficulty: "medium".to_string(),
             packages: Vec::new(),
+            pre_install: Vec::new(),
+            post_install: Vec::new(),
             copy_paths: Vec::new(),
             env_vars: HashMap::new(),
             user: "user".to_string(),
@@ -660,6 +770,8 @@ mod tests {
             category: "web".to_string(),
             difficulty: "medium".to_string(),
             packages: Vec::new(),
+            pre_install: Vec::new(),
+            post_install: Vec::new(),
             copy_paths: Vec::new(),
             env_vars: HashMap::new(),
             user: "user".to_string(),
@@ -684,6 +796,8 @@ mod tests {
             category: "web".to_string(),
             difficulty: "hard".to_string(),
             packages: Vec::new(),
+            pre_install: Vec::new(),
+            post_install: Vec::new(),
             copy_paths: Vec::new(),
             env_vars: HashMap::new(),
             user: "user".to_string(),
@@ -700,6 +814,97 @@ mod tests {
         assert!(yaml.contains("WEBSERVER_HOST"));
     }
 
+    fn workspace_config(task_id: &str) -> DockerfileConfig {
+        DockerfileConfig {
+            base_image: "swe-forge/ubuntu-24.04:latest".to_string(),
+            task_id: task_id.to_string(),
+            category: "web".to_string(),
+            difficulty: "medium".to_string(),
+            packages: Vec::new(),
+            pre_install: Vec::new(),
+            post_install: Vec::new(),
+            copy_paths: Vec::new(),
+            env_vars: HashMap::new(),
+            user: "user".to_string(),
+            workdir: "/home/user".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_validate_accepts_generated_compose() {
+        let mut builder = ComposeBuilder::new();
+        builder
+            .add_workspace(&workspace_config("test-005"))
+            .add_database("postgres")
+            .add_cache()
+            .add_webserver();
+
+        assert_eq!(builder.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_rejects_missing_dependency() {
+        let mut builder = ComposeBuilder::new();
+        builder.add_webserver();
+
+        assert_eq!(
+            builder.validate(),
+            Err(ComposeError::UnknownDependency {
+                service: "webserver".to_string(),
+                dependency: "workspace".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_structural_errors() {
+        let mut builder = ComposeBuilder::new();
+        builder.add_workspace(&workspace_config("test-006"));
+        let base = builder.config().clone();
+
+        assert_eq!(
+            ComposeConfig::default().validate(),
+            Err(ComposeError::NoServices)
+        );
+
+        let mut config = base.clone();
+        let mut copy = config.services["workspace"].clone();
+        copy.hostname = "workspace-2".to_string();
+        config.services.insert("workspace-2".to_string(), copy);
+        assert_eq!(
+            config.validate(),
+            Err(ComposeError::DuplicateService("workspace".to_string()))
+        );
+
+        let mut config = base.clone();
+        config.volumes.remove("task-results");
+        assert_eq!(
+            config.validate(),
+            Err(ComposeError::UndefinedVolume {
+                service: "workspace".to_string(),
+                volume: "task-results".to_string(),
+            })
+        );
+
+        let mut config = base;
+        config
+            .services
+            .get_mut("workspace")
+            .unwrap()
+            .volumes
+            .push("scratch:/home/user".to_string());
+        config
+            .volumes
+            .insert("scratch".to_string(), VolumeConfig {});
+        assert_eq!(
+            config.validate(),
+            Err(ComposeError::DuplicateVolume {
+                service: "workspace".to_string(),
+                target: "/home/user".to_string(),
+            })
+        );
+    }
+
     #[test]
     fn test_resource_limits_by_difficulty() {
         assert_eq!(
@@ -6,6 +6,8 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::error::DockerError;
+
 /// Base Ubuntu image with essential tools.
 pub const BASE_UBUNTU: &str = "swe_forge/ubuntu-24.04:latest";
 
@@ -21,6 +23,16 @@ pub const BASE_RUST: &str = "swe_forge/rust-1.80:latest";
 /// Multi-language image with Python, Node, Go, and Rust.
 pub const BASE_MULTI_LANG: &str = "swe_forge/multi-lang:latest";
 
+/// Language image families and their supported versions; the first entry is
+/// the default tag used when a task does not pin a version.
+const LANGUAGE_VERSIONS: &[(&str, &[&str])] = &[
+    ("python", &["3.13", "3.12", "3.11", "3.10", "3.9", "3.8"]),
+    ("node", &["22", "20", "18"]),
+    ("go", &["1.23", "1.22", "1.21"]),
+    ("rust", &["1.80", "1.79", "1.78", "1.77", "1.76", "1.75"]),
+    ("java", &["21", "17", "11"]),
+];
+
 /// Configuration for generating a Dockerfile.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DockerfileConfig {
@@ -34,6 +46,12 @@ pub struct DockerfileConfig {
     pub difficulty: String,
     /// System packages to install via apt-get.
     pub packages: Vec<String>,
+    /// Shell commands run as root before the package install.
+    #[serde(default)]
+    pub pre_install: Vec<String>,
+    /// Shell commands run as root after the package install.
+    #[serde(default)]
+    pub post_install: Vec<String>,
     /// Files to copy into the container as (source, destination) pairs.
     pub copy_paths: Vec<(String, String)>,
     /// Environment variables to set in the container.
@@ -52,6 +70,8 @@ impl Default for DockerfileConfig {
             category: String::new(),
             difficulty: "medium".to_string(),
             packages: Vec::new(),
+            pre_install: Vec::new(),
+            post_install: Vec::new(),
             copy_paths: Vec::new(),
             env_vars: HashMap::new(),
             user: "user".to_string(),
@@ -60,6 +80,23 @@ impl Default for DockerfileConfig {
     }
 }
 
+impl DockerfileConfig {
+    /// Shell commands for the validated install steps, in build order:
+    /// `pre_install`, the apt-get package install, then `post_install`.
+    pub fn install_commands(&self) -> Vec<String> {
+        let mut commands = filter_valid_install_commands(&self.pre_install);
+        let packages = filter_valid_packages(&self.packages);
+        if !packages.is_empty() {
+            commands.push(format!(
+                "apt-get update && apt-get install -y --no-install-recommends {} && rm -rf /var/lib/apt/lists/*",
+                packages.join(" ")
+            ));
+        }
+        commands.extend(filter_valid_install_commands(&self.post_install));
+        commands
+    }
+}
+
 /// Builder for generating Dockerfile content.
 #[derive(Debug, Clone)]
 pub struct DockerfileBuilder {
@@ -95,14 +132,24 @@ impl DockerfileBuilder {
         ));
         lines.push(String::new());
 
-        // Install additional packages if specified (with validation)
+        // Install additional packages and custom steps (with validation)
         let valid_packages = filter_valid_packages(&self.config.packages);
-        if !valid_packages.is_empty() {
+        let pre_install = filter_valid_install_commands(&self.config.pre_install);
+        let post_install = filter_valid_install_commands(&self.config.post_install);
+        if !valid_packages.is_empty() || !pre_install.is_empty() || !post_install.is_empty() {
             lines.push("USER root".to_string());
-            lines.push(format!(
-                "RUN apt-get update && apt-get install -y --no-install-recommends {} && rm -rf /var/lib/apt/lists/*",
-                valid_packages.join(" \\\n    ")
-            ));
+            for command in &pre_install {
+                lines.push(format!("RUN {}", command));
+            }
+            if !valid_packages.is_empty() {
+                lines.push(format!(
+                    "RUN apt-get update && apt-get install -y --no-install-recommends {} && rm -rf /var/lib/apt/lists/*",
+                    valid_packages.join(" \\\n    ")
+                ));
+            }
+            for command in &post_install {
+                lines.push(format!("RUN {}", command));
+            }
             lines.push(String::new());
         }
 
@@ -177,6 +224,85 @@ pub fn filter_valid_packages(packages: &[String]) -> Vec<String> {
         .collect()
 }
 
+/// Validate a custom install command before it is emitted as a `RUN` line.
+///
+/// Commands must be a single non-empty line: newlines, carriage returns,
+/// trailing line continuations and NUL bytes are rejected because they would
+/// let the command smuggle additional Dockerfile instructions into the build.
+pub fn is_valid_install_command(command: &str) -> bool {
+    let trimmed = command.trim();
+    !trimmed.is_empty()
+        && !trimmed.ends_with('\\')
+        && !command.chars().any(|c| matches!(c, '\n' | '\r' | '\0'))
+}
+
+/// Filter and return only valid install commands, trimmed.
+///
+/// Invalid commands are silently dropped, mirroring `filter_valid_packages`.
+pub fn filter_valid_install_commands(commands: &[String]) -> Vec<String> {
+    commands
+        .iter()
+        .filter(|c| is_valid_install_command(c))
+        .map(|c| c.trim().to_string())
+        .collect()
+}
+
+/// Canonical image family for a language name, matching the version keys
+/// used by `SweTask::install_defaults` (e.g. `typescript` -> `node`).
+pub fn language_family(language: &str) -> Option<&'static str> {
+    match language.trim().to_lowercase().as_str() {
+        "python" | "py" => Some("python"),
+        "javascript" | "typescript" | "js" | "ts" | "node" => Some("node"),
+        "go" | "golang" => Some("go"),
+        "rust" => Some("rust"),
+        "java" => Some("java"),
+        _ => None,
+    }
+}
+
+/// Select the base image for a language, pinned to the requested version.
+///
+/// Patch versions are matched to their supported minor release (`3.9.18` ->
+/// `3.9`). Without a version the family's default tag is used.
+///
+/// # Errors
+///
+/// Returns `DockerError::InvalidConfig` for unknown languages or versions
+/// that have no published image.
+pub fn select_base_image(language: &str, version: Option<&str>) -> Result<String, DockerError> {
+    let family = language_family(language).ok_or_else(|| {
+        DockerError::InvalidConfig(format!("Unsupported language '{}'", language))
+    })?;
+    let supported = LANGUAGE_VERSIONS
+        .iter()
+        .find(|(name, _)| *name == family)
+        .map(|(_, versions)| *versions)
+        .unwrap_or_default();
+
+    let tag = match version.map(str::trim).filter(|v| !v.is_empty()) {
+        None => supported.first().copied(),
+        Some(requested) => {
+            let requested = requested.trim_start_matches('v');
+            supported.iter().copied().find(|v| {
+                requested == *v
+                    || requested
+                        .strip_prefix(v)
+                        .is_some_and(|rest| rest.starts_with('.'))
+            })
+        }
+    }
+    .ok_or_else(|| {
+        DockerError::InvalidConfig(format!(
+            "Unsupported {} version '{}' (supported: {})",
+            family,
+            version.unwrap_or_default(),
+            supported.join(", ")
+        ))
+    })?;
+
+    Ok(format!("swe_forge/{}-{}:latest", family, tag))
+}
+
 /// Select the appropriate base image based on task category and requirements.
 ///
 /// # Arguments
@@ -185,7 +311,7 @@ pub fn filter_valid_packages(packages: &[String]) -> Vec<String> {
 ///
 /// # Returns
 /// The base image name to use for the task.
-pub fn select_base_image(category: &str, requirements: &[String]) -> String {
+pub fn select_base_image_for_category(category: &str, requirements: &[String]) -> String {
     // Check requirements for specific language needs
     let needs_python = requirements
         .iter()
@@ -241,6 +367,8 @@ mod tests {
             category: "file-operations".to_string(),
             difficulty: "easy".to_string(),
             packages: Vec::new(),
+            pre_install: Vec::new(),
+            post_install: Vec::new(),
             copy_paths: Vec::new(),
             env_vars: HashMap::new(),
             user: "user".to_string(),
@@ -264,6 +392,8 @@ mod tests {
             category: "debugging".to_string(),
             difficulty: "medium".to_string(),
             packages: vec!["vim".to_string(), "curl".to_string()],
+            pre_install: Vec::new(),
+            post_install: Vec::new(),
             copy_paths: Vec::new(),
             env_vars: HashMap::new(),
             user: "user".to_string(),
@@ -277,6 +407,63 @@ mod tests {
         assert!(dockerfile.contains("curl"));
     }
 
+    #[test]
+    fn test_dockerfile_builder_with_install_hooks() {
+        let config = DockerfileConfig {
+            packages: vec!["curl".to_string()],
+            pre_install: vec![
+                "echo 'deb http://deb.example.org stable main' > /etc/apt/sources.list.d/x.list"
+                    .to_string(),
+                "true\nUSER root".to_string(), // Invalid - injects an instruction
+            ],
+            post_install: vec![
+                "pip install --no-cache-dir uv".to_string(),
+                "  /opt/setup.sh  ".to_string(),
+            ],
+            ..Default::default()
+        };
+
+        let dockerfile = DockerfileBuilder::new(config).build();
+        let runs: Vec<&str> = dockerfile
+            .lines()
+            .filter(|line| line.starts_with("RUN "))
+            .collect();
+
+        assert_eq!(runs.len(), 4);
+        assert!(runs[0].starts_with("RUN echo 'deb http://deb.example.org"));
+        assert!(runs[1].starts_with("RUN apt-get update"));
+        assert_eq!(runs[2], "RUN pip install --no-cache-dir uv");
+        assert_eq!(runs[3], "RUN /opt/setup.sh");
+        assert!(!dockerfile.contains("true\nUSER root"));
+    }
+
+    #[test]
+    fn test_install_commands_order() {
+        let config = DockerfileConfig {
+            packages: vec!["curl".to_string(), "jq; rm -rf /".to_string()],
+            pre_install: vec!["update-ca-certificates".to_string()],
+            post_install: vec!["pip install pytest".to_string()],
+            ..Default::default()
+        };
+
+        let commands = config.install_commands();
+        assert_eq!(commands.len(), 3);
+        assert_eq!(commands[0], "update-ca-certificates");
+        assert!(commands[1].contains("--no-install-recommends curl &&"));
+        assert_eq!(commands[2], "pip install pytest");
+        assert!(DockerfileConfig::default().install_commands().is_empty());
+    }
+
+    #[test]
+    fn test_is_valid_install_command() {
+        assert!(is_valid_install_command(
+            "apt-get install -y jq && jq --version"
+        ));
+        assert!(!is_valid_install_command("   "));
+        assert!(!is_valid_install_command("echo hi \\"));
+        assert!(!is_valid_install_command("echo hi\r\nCMD [\"sh\"]"));
+    }
+
     #[test]
     fn test_dockerfile_builder_with_env_vars() {
         let mut env_vars = HashMap::new();
@@ -289,6 +476,8 @@ mod tests {
             category: "data-science".to_string(),
             difficulty: "hard".to_string(),
             packages: Vec::new(),
+            pre_install: Vec::new(),
+            post_install: Vec::new(),
             copy_paths: Vec::new(),
             env_vars,
             user: "user".to_string(),
@@ -304,13 +493,19 @@ mod tests {
     #[test]
     fn test_select_base_image_by_category() {
         assert_eq!(
-            select_base_image("data-science", &[]),
+            select_base_image_for_category("data-science", &[]),
             BASE_PYTHON.to_string()
         );
-        assert_eq!(select_base_image("web", &[]), BASE_NODE.to_string());
-        assert_eq!(select_base_image("systems", &[]), BASE_RUST.to_string());
         assert_eq!(
-            select_base_image("file-operations", &[]),
+            select_base_image_for_category("web", &[]),
+            BASE_NODE.to_string()
+        );
+        assert_eq!(
+            select_base_image_for_category("systems", &[]),
+            BASE_RUST.to_string()
+        );
+        assert_eq!(
+            select_base_image_for_category("file-operations", &[]),
             BASE_UBUNTU.to_string()
         );
     }
@@ -319,23 +514,57 @@ mod tests {
     fn test_select_base_image_by_requirements() {
         let python_reqs = vec!["python3".to_string(), "pip".to_string()];
         assert_eq!(
-            select_base_image("general", &python_reqs),
+            select_base_image_for_category("general", &python_reqs),
             BASE_PYTHON.to_string()
         );
 
         let node_reqs = vec!["npm".to_string()];
         assert_eq!(
-            select_base_image("general", &node_reqs),
+            select_base_image_for_category("general", &node_reqs),
             BASE_NODE.to_string()
         );
 
         let multi_reqs = vec!["python".to_string(), "node".to_string()];
         assert_eq!(
-            select_base_image("general", &multi_reqs),
+            select_base_image_for_category("general", &multi_reqs),
             BASE_MULTI_LANG.to_string()
         );
     }
 
+    #[test]
+    fn test_select_base_image_by_language_version() {
+        assert_eq!(
+            select_base_image("python", Some("3.9")).unwrap(),
+            "swe_forge/python-3.9:latest"
+        );
+        assert_eq!(
+            select_base_image("Python", Some("3.9.18")).unwrap(),
+            "swe_forge/python-3.9:latest"
+        );
+        assert_eq!(select_base_image("python", None).unwrap(), BASE_PYTHON);
+        assert_eq!(select_base_image("typescript", None).unwrap(), BASE_NODE);
+        assert_eq!(
+            select_base_image("go", Some("1.22")).unwrap(),
+            "swe_forge/go-1.22:latest"
+        );
+    }
+
+    #[test]
+    fn test_select_base_image_rejects_unsupported() {
+        assert!(matches!(
+            select_base_image("python", Some("2.7")),
+            Err(DockerError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            select_base_image("python", Some("3.1")),
+            Err(DockerError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            select_base_image("cobol", None),
+            Err(DockerError::InvalidConfig(_))
+        ));
+    }
+
     #[test]
     fn test_escape_env_value() {
         assert_eq!(escape_env_value("simple"), "simple");
@@ -7,13 +7,15 @@ pub mod compose;
 pub mod dockerfile;
 pub mod resources;
 
+use crate::error::ComposeError;
+
 pub use compose::{
     ComposeBuild, ComposeBuilder, ComposeConfig, ComposeService, DeployConfig, HealthCheck,
     NetworkConfig, ResourceLimitsConfig, ResourcesConfig, VolumeConfig,
 };
 pub use dockerfile::{
-    select_base_image, DockerfileBuilder, DockerfileConfig, BASE_MULTI_LANG, BASE_NODE,
-    BASE_PYTHON, BASE_RUST, BASE_UBUNTU,
+    language_family, select_base_image, select_base_image_for_category, DockerfileBuilder,
+    DockerfileConfig, BASE_MULTI_LANG, BASE_NODE, BASE_PYTHON, BASE_RUST, BASE_UBUNTU,
 };
 pub use resources::{
     apply_resource_limits, create_secure_volumes, ContainerConfig, NetworkMode, ResourceLimits,
@@ -33,11 +35,14 @@ pub struct DockerEnvironment {
 
 impl DockerEnvironment {
     /// Create a new Docker environment from a Dockerfile configuration.
-    pub fn new(config: &DockerfileConfig) -> Self {
+    ///
+    /// Returns an error if the generated compose configuration is invalid.
+    pub fn new(config: &DockerfileConfig) -> Result<Self, ComposeError> {
         let dockerfile = DockerfileBuilder::new(config.clone()).build();
 
         let mut compose_builder = ComposeBuilder::new();
         compose_builder.add_workspace(config);
+        compose_builder.validate()?;
         let compose = compose_builder.build();
 
         let limits = apply_resource_limits(&config.difficulty);
@@ -50,20 +55,22 @@ impl DockerEnvironment {
             network_mode: resources::network_mode_from_difficulty(&config.difficulty),
         };
 
-        Self {
+        Ok(Self {
             dockerfile,
             compose,
             container_config,
-        }
+        })
     }
 
     /// Create a Docker environment with additional services (database, cache, webserver).
+    ///
+    /// Returns an error if the generated compose configuration is invalid.
     pub fn with_services(
         config: &DockerfileConfig,
         database: Option<&str>,
         include_cache: bool,
         include_webserver: bool,
-    ) -> Self {
+    ) -> Result<Self, ComposeError> {
         let dockerfile = DockerfileBuilder::new(config.clone()).build();
 
         let mut compose_builder = ComposeBuilder::new();
@@ -81,6 +88,7 @@ impl DockerEnvironment {
             compose_builder.add_webserver();
         }
 
+        compose_builder.validate()?;
         let compose = compose_builder.build();
 
         let limits = apply_resource_limits(&config.difficulty);
@@ -93,10 +101,10 @@ impl DockerEnvironment {
             network_mode: resources::network_mode_from_difficulty(&config.difficulty),
         };
 
-        Self {
+        Ok(Self {
             dockerfile,
             compose,
             container_config,
-        }
+        })
     }
 }
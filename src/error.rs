@@ -104,15 +104,28 @@ pub enum LlmError {
     #[error("HTTP request failed: {0}")]
     RequestFailed(String),
 
+    #[error("Request timed out: {0}")]
+    Timeout(String),
+
     #[error("Failed to parse LLM response: {0}")]
     ParseError(String),
 
-    #[error("Rate limited: {0}")]
-    RateLimited(String),
+    #[error("Rate limited: {message}")]
+    RateLimited {
+        message: String,
+        /// Delay requested by the provider's `Retry-After` header, if any.
+        retry_after: Option<std::time::Duration>,
+    },
 
     #[error("Invalid model: {0}")]
     InvalidModel(String),
 
+    #[error("Response does not match JSON schema: {}", errors.join("; "))]
+    SchemaViolation { errors: Vec<String> },
+
+    #[error("JSON schema uses keywords that cannot be validated: {}", keywords.join(", "))]
+    UnsupportedSchema { keywords: Vec<String> },
+
     #[error("Context length exceeded: {limit} tokens")]
     ContextLengthExceeded { limit: u32 },
 
@@ -129,8 +142,18 @@ pub enum TemplateError {
     #[error("Template '{0}' not found")]
     NotFound(String),
 
-    #[error("Failed to parse template file '{path}': {message}")]
-    ParseError { path: String, message: String },
+    #[error(
+        "Failed to parse template file '{path}'{}: {message}",
+        format_location(*.line, *.column)
+    )]
+    ParseError {
+        path: String,
+        message: String,
+        /// 1-based line of the error, when the parser reports one.
+        line: Option<usize>,
+        /// 1-based column of the error, when the parser reports one.
+        column: Option<usize>,
+    },
 
     #[error("Invalid variable definition for '{variable}': {message}")]
     InvalidVariableDefinition { variable: String, message: String },
@@ -193,6 +216,27 @@ pub enum TemplateError {
     DuplicateTemplateId(String),
 }
 
+impl TemplateError {
+    /// Build a `ParseError` from a YAML parse failure, keeping its location.
+    pub fn from_yaml(path: impl Into<String>, err: &serde_yaml::Error) -> Self {
+        let location = err.location();
+        Self::ParseError {
+            path: path.into(),
+            message: err.to_string(),
+            line: location.as_ref().map(|l| l.line()),
+            column: location.as_ref().map(|l| l.column()),
+        }
+    }
+}
+
+fn format_location(line: Option<usize>, column: Option<usize>) -> String {
+    match (line, column) {
+        (Some(line), Some(column)) => format!(" at line {line}, column {column}"),
+        (Some(line), None) => format!(" at line {line}"),
+        _ => String::new(),
+    }
+}
+
 /// Errors that can occur during validation operations.
 #[derive(Debug, Error)]
 pub enum ValidationError {
@@ -248,6 +292,9 @@ pub enum DockerError {
     #[error("Docker daemon not available: {0}")]
     DaemonUnavailable(String),
 
+    #[error("Invalid Docker configuration: {0}")]
+    InvalidConfig(String),
+
     #[error("Invalid Dockerfile: {0}")]
     InvalidDockerfile(String),
 
@@ -261,6 +308,40 @@ pub enum DockerError {
     Io(#[from] std::io::Error),
 }
 
+/// Errors found when validating a docker-compose configuration.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ComposeError {
+    #[error("Compose file version is empty")]
+    MissingVersion,
+
+    #[error("Compose file defines no services")]
+    NoServices,
+
+    #[error("Service '{service}' is missing required field '{field}'")]
+    MissingField { service: String, field: String },
+
+    #[error("Service '{service}' must set exactly one of 'image' or 'build'")]
+    AmbiguousSource { service: String },
+
+    #[error("Duplicate service name '{0}'")]
+    DuplicateService(String),
+
+    #[error("Duplicate container name '{0}'")]
+    DuplicateContainerName(String),
+
+    #[error("Service '{service}' mounts more than one volume at '{target}'")]
+    DuplicateVolume { service: String, target: String },
+
+    #[error("Service '{service}' uses undeclared volume '{volume}'")]
+    UndefinedVolume { service: String, volume: String },
+
+    #[error("Service '{service}' uses undeclared network '{network}'")]
+    UndefinedNetwork { service: String, network: String },
+
+    #[error("Service '{service}' depends on unknown service '{dependency}'")]
+    UnknownDependency { service: String, dependency: String },
+}
+
 /// Errors that can occur during export operations.
 #[derive(Debug, Error)]
 pub enum ExportError {
@@ -300,3 +381,85 @@ pub enum ExportError {
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
 }
+
+/// Errors that can occur when importing externally-authored task directories.
+#[derive(Debug, Error)]
+pub enum ImportError {
+    #[error("Task path '{0}' is not a directory")]
+    NotADirectory(String),
+
+    #[error("Task directory '{dir}' is missing required file '{file}'")]
+    MissingFile { dir: String, file: String },
+
+    #[error(
+        "Failed to parse '{path}'{}: {message}",
+        format_location(*.line, *.column)
+    )]
+    Parse {
+        path: String,
+        message: String,
+        /// 1-based line of the error, when the parser reports one.
+        line: Option<usize>,
+        /// 1-based column of the error, when the parser reports one.
+        column: Option<usize>,
+    },
+
+    #[error("Task '{task}' has no {field}")]
+    MissingField { task: String, field: String },
+
+    #[error("Invalid task id '{0}': must not contain '/', '\\' or '..'")]
+    InvalidTaskId(String),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+impl ImportError {
+    /// Build a `Parse` error from a YAML parse failure, keeping its location.
+    pub fn from_yaml(path: impl Into<String>, err: &serde_yaml::Error) -> Self {
+        let location = err.location();
+        Self::Parse {
+            path: path.into(),
+            message: err.to_string(),
+            line: location.as_ref().map(|l| l.line()),
+            column: location.as_ref().map(|l| l.column()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_template_parse_error_captures_location() {
+        let yaml = "id: broken\nvariables:\n  count: [1, 2\n";
+        let err = serde_yaml::from_str::<serde_yaml::Value>(yaml).unwrap_err();
+        let parse_error = TemplateError::from_yaml("templates/broken.yaml", &err);
+
+        match &parse_error {
+            TemplateError::ParseError { line, column, .. } => {
+                assert_eq!(*line, Some(4));
+                assert_eq!(*column, Some(1));
+            }
+            other => panic!("expected ParseError, got {other:?}"),
+        }
+        assert!(parse_error.to_string().starts_with(
+            "Failed to parse template file 'templates/broken.yaml' at line 4, column 1: did not find"
+        ));
+    }
+
+    #[test]
+    fn test_template_parse_error_without_location() {
+        let parse_error = TemplateError::ParseError {
+            path: "t.yaml".to_string(),
+            message: "empty document".to_string(),
+            line: None,
+            column: None,
+        };
+        assert_eq!(
+            parse_error.to_string(),
+            "Failed to parse template file 't.yaml': empty document"
+        );
+    }
+}
@@ -7,7 +7,9 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::error::DockerError;
-use crate::execution::docker_client::{ContainerConfig, ContainerStatusInfo, DockerClient};
+use crate::execution::docker_client::{
+    ContainerConfig, ContainerStatusInfo, DockerClient, LogLine,
+};
 
 // Re-export ExecResult from docker_client for convenience
 pub use crate::execution::docker_client::ExecResult;
@@ -197,6 +199,31 @@ impl Container {
         &self,
         client: &DockerClient,
         cmd: &[&str],
+    ) -> Result<ExecResult, DockerError> {
+        self.exec_streaming(client, cmd, |_| {}).await
+    }
+
+    /// Executes a command in the container, calling `on_line` for each output
+    /// line as it is produced.
+    ///
+    /// The complete output is still returned once the command finishes.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Container::exec`].
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let result = container
+    ///     .exec_streaming(&client, &["pytest", "-q"], |line| println!("{}", line.line))
+    ///     .await?;
+    /// ```
+    pub async fn exec_streaming(
+        &self,
+        client: &DockerClient,
+        cmd: &[&str],
+        on_line: impl FnMut(LogLine),
     ) -> Result<ExecResult, DockerError> {
         if self.status != ContainerStatus::Running {
             return Err(DockerError::RunFailed(format!(
@@ -205,7 +232,7 @@ impl Container {
             )));
         }
 
-        client.exec_command(&self.id, cmd).await
+        client.exec_command_streaming(&self.id, cmd, on_line).await
     }
 
     /// Stops the container if running.
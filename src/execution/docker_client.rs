@@ -8,10 +8,11 @@ use bollard::container::{
     RemoveContainerOptions, StartContainerOptions, StopContainerOptions,
 };
 use bollard::exec::{CreateExecOptions, StartExecResults};
-use bollard::image::CreateImageOptions;
+use bollard::image::{CommitContainerOptions, CreateImageOptions};
 use bollard::models::HostConfig;
-use bollard::Docker;
+use bollard::{Docker, API_DEFAULT_VERSION};
 use futures::StreamExt;
+use std::path::PathBuf;
 
 use crate::error::DockerError;
 use crate::execution::resources::ExecutionLimits;
@@ -136,6 +137,165 @@ pub struct ExecResult {
     pub stderr: String,
 }
 
+/// Output stream a log line came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogStream {
+    Stdout,
+    Stderr,
+}
+
+/// A single line of command output, delivered while the command runs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogLine {
+    /// Stream the line was written to.
+    pub stream: LogStream,
+    /// Line content without the trailing newline.
+    pub line: String,
+}
+
+/// Drains an exec output stream, calling `on_line` per complete line and
+/// returning the full stdout and stderr.
+async fn collect_exec_output<S>(
+    mut output: S,
+    mut on_line: impl FnMut(LogLine),
+) -> Result<(String, String), DockerError>
+where
+    S: futures::Stream<Item = Result<LogOutput, bollard::errors::Error>> + Unpin,
+{
+    let mut stdout = String::new();
+    let mut stderr = String::new();
+    let mut stdout_pending = String::new();
+    let mut stderr_pending = String::new();
+
+    while let Some(chunk) = output.next().await {
+        let (stream, text) = match chunk {
+            Ok(LogOutput::StdOut { message }) => (
+                LogStream::Stdout,
+                String::from_utf8_lossy(&message).into_owned(),
+            ),
+            Ok(LogOutput::StdErr { message }) => (
+                LogStream::Stderr,
+                String::from_utf8_lossy(&message).into_owned(),
+            ),
+            Ok(_) => continue,
+            Err(e) => {
+                return Err(DockerError::RunFailed(format!("Error reading output: {e}")));
+            }
+        };
+
+        let (full, pending) = match stream {
+            LogStream::Stdout => (&mut stdout, &mut stdout_pending),
+            LogStream::Stderr => (&mut stderr, &mut stderr_pending),
+        };
+        full.push_str(&text);
+        pending.push_str(&text);
+        while let Some(pos) = pending.find('\n') {
+            let line = pending[..pos].trim_end_matches('\r').to_string();
+            pending.drain(..=pos);
+            on_line(LogLine { stream, line });
+        }
+    }
+
+    for (stream, pending) in [
+        (LogStream::Stdout, stdout_pending),
+        (LogStream::Stderr, stderr_pending),
+    ] {
+        if !pending.is_empty() {
+            on_line(LogLine {
+                stream,
+                line: pending,
+            });
+        }
+    }
+
+    Ok((stdout, stderr))
+}
+
+/// Default local Docker socket.
+const DEFAULT_DOCKER_SOCKET: &str = "/var/run/docker.sock";
+
+/// Request timeout in seconds for Docker API calls.
+const DOCKER_TIMEOUT_SECS: u64 = 120;
+
+/// Files required in `DOCKER_CERT_PATH` for TLS connections.
+const TLS_CERT_FILES: [&str; 3] = ["ca.pem", "cert.pem", "key.pem"];
+
+/// How to reach a Docker daemon, resolved from the standard `DOCKER_*` variables.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DockerConnection {
+    /// Local Unix socket at the given path.
+    Socket(String),
+    /// Unencrypted TCP connection to `address`.
+    Http { address: String },
+    /// TLS connection to `address` using `ca.pem`, `cert.pem` and `key.pem` from `cert_path`.
+    Tls { address: String, cert_path: PathBuf },
+}
+
+impl DockerConnection {
+    /// Resolves the connection from `DOCKER_HOST`, `DOCKER_TLS_VERIFY` and `DOCKER_CERT_PATH`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DockerError::InvalidConfig` for unsupported host schemes or when
+    /// TLS is requested without a usable certificate directory.
+    pub fn from_env() -> Result<Self, DockerError> {
+        Self::from_vars(|key| std::env::var(key).ok())
+    }
+
+    /// Resolves the connection from a variable lookup (see [`DockerConnection::from_env`]).
+    pub fn from_vars(lookup: impl Fn(&str) -> Option<String>) -> Result<Self, DockerError> {
+        let var = |key: &str| lookup(key).filter(|v| !v.trim().is_empty());
+
+        let Some(host) = var("DOCKER_HOST") else {
+            return Ok(Self::Socket(DEFAULT_DOCKER_SOCKET.to_string()));
+        };
+        let host = host.trim().to_string();
+
+        if let Some(path) = host.strip_prefix("unix://") {
+            return Ok(Self::Socket(path.to_string()));
+        }
+
+        let address = if let Some(rest) = host.strip_prefix("tcp://") {
+            rest
+        } else if let Some(rest) = host.strip_prefix("https://") {
+            rest
+        } else if let Some(rest) = host.strip_prefix("http://") {
+            rest
+        } else {
+            return Err(DockerError::InvalidConfig(format!(
+                "unsupported DOCKER_HOST '{host}' (expected unix://, tcp://, http:// or https://)"
+            )));
+        };
+        if address.is_empty() {
+            return Err(DockerError::InvalidConfig(format!(
+                "DOCKER_HOST '{host}' has no address"
+            )));
+        }
+
+        let tls = host.starts_with("https://")
+            || var("DOCKER_TLS_VERIFY").is_some_and(|v| v.trim() != "0");
+        if !tls {
+            return Ok(Self::Http {
+                address: address.to_string(),
+            });
+        }
+
+        let cert_path = var("DOCKER_CERT_PATH")
+            .map(PathBuf::from)
+            .or_else(|| var("HOME").map(|home| PathBuf::from(home).join(".docker")))
+            .ok_or_else(|| {
+                DockerError::InvalidConfig(
+                    "DOCKER_TLS_VERIFY is set but neither DOCKER_CERT_PATH nor HOME is".to_string(),
+                )
+            })?;
+
+        Ok(Self::Tls {
+            address: address.to_string(),
+            cert_path,
+        })
+    }
+}
+
 /// Docker client wrapper for container operations.
 pub struct DockerClient {
     docker: Docker,
@@ -154,6 +314,55 @@ impl DockerClient {
         Ok(Self { docker })
     }
 
+    /// Creates a Docker client from the `DOCKER_HOST`, `DOCKER_TLS_VERIFY` and
+    /// `DOCKER_CERT_PATH` environment variables, supporting remote daemons.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DockerError::InvalidConfig` if the variables are inconsistent
+    /// or TLS certificates are missing, and `DockerError::DaemonUnavailable`
+    /// if the client cannot be created.
+    pub fn from_env() -> Result<Self, DockerError> {
+        Self::connect(&DockerConnection::from_env()?)
+    }
+
+    /// Creates a Docker client for an explicit connection.
+    pub fn connect(connection: &DockerConnection) -> Result<Self, DockerError> {
+        let docker = match connection {
+            DockerConnection::Socket(path) => {
+                Docker::connect_with_socket(path, DOCKER_TIMEOUT_SECS, API_DEFAULT_VERSION)
+            }
+            DockerConnection::Http { address } => {
+                Docker::connect_with_http(address, DOCKER_TIMEOUT_SECS, API_DEFAULT_VERSION)
+            }
+            DockerConnection::Tls { address, cert_path } => {
+                let missing: Vec<&str> = TLS_CERT_FILES
+                    .iter()
+                    .copied()
+                    .filter(|file| !cert_path.join(file).is_file())
+                    .collect();
+                if !missing.is_empty() {
+                    return Err(DockerError::InvalidConfig(format!(
+                        "missing {} in DOCKER_CERT_PATH {}",
+                        missing.join(", "),
+                        cert_path.display()
+                    )));
+                }
+                Docker::connect_with_ssl(
+                    address,
+                    &cert_path.join("key.pem"),
+                    &cert_path.join("cert.pem"),
+                    &cert_path.join("ca.pem"),
+                    DOCKER_TIMEOUT_SECS,
+                    API_DEFAULT_VERSION,
+                )
+            }
+        }
+        .map_err(|e| DockerError::DaemonUnavailable(format!("Failed to connect: {e}")))?;
+
+        Ok(Self { docker })
+    }
+
     /// Creates a new Docker client from an existing bollard Docker instance.
     pub fn from_docker(docker: Docker) -> Self {
         Self { docker }
@@ -267,6 +476,20 @@ impl DockerClient {
     ///
     /// `ExecResult` containing exit code, stdout, and stderr.
     pub async fn exec_command(&self, id: &str, cmd: &[&str]) -> Result<ExecResult, DockerError> {
+        self.exec_command_streaming(id, cmd, |_| {}).await
+    }
+
+    /// Executes a command inside a running container, reporting output as it arrives.
+    ///
+    /// `on_line` is called once per complete stdout/stderr line (without the
+    /// trailing newline); a final unterminated line is reported when the
+    /// command ends. The full output is still returned in the `ExecResult`.
+    pub async fn exec_command_streaming(
+        &self,
+        id: &str,
+        cmd: &[&str],
+        on_line: impl FnMut(LogLine),
+    ) -> Result<ExecResult, DockerError> {
         let exec_options = CreateExecOptions {
             cmd: Some(cmd.to_vec()),
             attach_stdout: Some(true),
@@ -287,25 +510,12 @@ impl DockerClient {
             .await
             .map_err(|e| DockerError::RunFailed(format!("Failed to start exec: {e}")))?;
 
-        let mut stdout = String::new();
-        let mut stderr = String::new();
-
-        if let StartExecResults::Attached { mut output, .. } = start_result {
-            while let Some(chunk) = output.next().await {
-                match chunk {
-                    Ok(LogOutput::StdOut { message }) => {
-                        stdout.push_str(&String::from_utf8_lossy(&message));
-                    }
-                    Ok(LogOutput::StdErr { message }) => {
-                        stderr.push_str(&String::from_utf8_lossy(&message));
-                    }
-                    Ok(_) => {}
-                    Err(e) => {
-                        return Err(DockerError::RunFailed(format!("Error reading output: {e}")));
-                    }
-                }
+        let (stdout, stderr) = match start_result {
+            StartExecResults::Attached { output, .. } => {
+                collect_exec_output(output, on_line).await?
             }
-        }
+            StartExecResults::Detached => (String::new(), String::new()),
+        };
 
         // Get exit code from exec inspect
         let exec_info = self
@@ -416,6 +626,34 @@ impl DockerClient {
         Ok(())
     }
 
+    /// Commits a container's filesystem to a new tagged image.
+    ///
+    /// # Returns
+    ///
+    /// The ID of the created image.
+    pub async fn commit_container(
+        &self,
+        id: &str,
+        repo: &str,
+        tag: &str,
+    ) -> Result<String, DockerError> {
+        let options = CommitContainerOptions {
+            container: id,
+            repo,
+            tag,
+            pause: true,
+            ..Default::default()
+        };
+
+        let commit = self
+            .docker
+            .commit_container(options, Config::<String>::default())
+            .await
+            .map_err(|e| DockerError::BuildFailed(format!("Failed to commit container: {e}")))?;
+
+        Ok(commit.id.unwrap_or_default())
+    }
+
     /// Checks if an image exists locally.
     pub async fn image_exists(&self, image: &str) -> bool {
         self.docker.inspect_image(image).await.is_ok()
@@ -456,6 +694,120 @@ impl DockerClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashMap;
+
+    fn resolve(vars: &[(&str, &str)]) -> Result<DockerConnection, DockerError> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        DockerConnection::from_vars(|key| vars.get(key).cloned())
+    }
+
+    #[tokio::test]
+    async fn test_collect_exec_output_calls_back_per_line() {
+        let chunks = vec![
+            Ok(LogOutput::StdOut {
+                message: "building\nstep 1".into(),
+            }),
+            Ok(LogOutput::StdErr {
+                message: "warning: slow\n".into(),
+            }),
+            Ok(LogOutput::StdOut {
+                message: " done\nfinished".into(),
+            }),
+        ];
+
+        let mut lines = Vec::new();
+        let (stdout, stderr) =
+            collect_exec_output(futures::stream::iter(chunks), |line| lines.push(line))
+                .await
+                .unwrap();
+
+        assert_eq!(stdout, "building\nstep 1 done\nfinished");
+        assert_eq!(stderr, "warning: slow\n");
+        let rendered: Vec<(LogStream, &str)> =
+            lines.iter().map(|l| (l.stream, l.line.as_str())).collect();
+        assert_eq!(
+            rendered,
+            vec![
+                (LogStream::Stdout, "building"),
+                (LogStream::Stderr, "warning: slow"),
+                (LogStream::Stdout, "step 1 done"),
+                (LogStream::Stdout, "finished"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_docker_connection_from_env_vars() {
+        assert_eq!(
+            resolve(&[]).unwrap(),
+            DockerConnection::Socket(DEFAULT_DOCKER_SOCKET.to_string())
+        );
+        assert_eq!(
+            resolve(&[("DOCKER_HOST", "unix:///run/user/1000/docker.sock")]).unwrap(),
+            DockerConnection::Socket("/run/user/1000/docker.sock".to_string())
+        );
+        assert_eq!(
+            resolve(&[("DOCKER_HOST", "tcp://10.0.0.5:2375")]).unwrap(),
+            DockerConnection::Http {
+                address: "10.0.0.5:2375".to_string()
+            }
+        );
+        assert_eq!(
+            resolve(&[
+                ("DOCKER_HOST", "tcp://build.example.com:2376"),
+                ("DOCKER_TLS_VERIFY", "1"),
+                ("DOCKER_CERT_PATH", "/certs"),
+            ])
+            .unwrap(),
+            DockerConnection::Tls {
+                address: "build.example.com:2376".to_string(),
+                cert_path: PathBuf::from("/certs"),
+            }
+        );
+        assert_eq!(
+            resolve(&[
+                ("DOCKER_HOST", "tcp://build.example.com:2376"),
+                ("DOCKER_TLS_VERIFY", "1"),
+                ("HOME", "/home/dev"),
+            ])
+            .unwrap(),
+            DockerConnection::Tls {
+                address: "build.example.com:2376".to_string(),
+                cert_path: PathBuf::from("/home/dev/.docker"),
+            }
+        );
+    }
+
+    #[test]
+    fn test_docker_connection_misconfiguration() {
+        assert!(matches!(
+            resolve(&[("DOCKER_HOST", "ssh://dev@build")]),
+            Err(DockerError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            resolve(&[("DOCKER_HOST", "tcp://")]),
+            Err(DockerError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            resolve(&[
+                ("DOCKER_HOST", "tcp://host:2376"),
+                ("DOCKER_TLS_VERIFY", "1")
+            ]),
+            Err(DockerError::InvalidConfig(_))
+        ));
+
+        let empty = tempfile::tempdir().unwrap();
+        let err = DockerClient::connect(&DockerConnection::Tls {
+            address: "host:2376".to_string(),
+            cert_path: empty.path().to_path_buf(),
+        })
+        .err()
+        .unwrap();
+        assert!(err.to_string().contains("ca.pem"));
+    }
 
     #[test]
     fn test_container_config_builder() {
@@ -31,5 +31,5 @@ pub mod docker_client;
 pub mod resources;
 
 pub use container::{Container, ContainerStatus, ExecResult};
-pub use docker_client::DockerClient;
+pub use docker_client::{DockerClient, DockerConnection, LogLine, LogStream};
 pub use resources::{get_execution_limits, ExecutionLimits};
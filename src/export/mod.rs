@@ -1,11 +1,16 @@
 //! Export module for SWE mining outputs.
 //!
-//! Provides Parquet dataset export and HuggingFace Hub upload.
+//! Provides Parquet dataset export, terminal-bench task directories, and
+//! HuggingFace Hub upload.
 
 pub mod dataset;
 pub mod hf_uploader;
 pub mod parquet_writer;
+pub mod terminal_bench;
 
 pub use dataset::{download_dataset, load_dataset, DatasetConfig, DatasetManager, DatasetSummary};
 pub use hf_uploader::{HfUploadConfig, HfUploader};
 pub use parquet_writer::{read_parquet, write_parquet, write_parquet_bytes};
+pub use terminal_bench::{
+    TaskImporter, TerminalBenchExporter, TerminalBenchTask, TerminalBenchVerification,
+};
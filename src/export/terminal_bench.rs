@@ -0,0 +1,538 @@
+//! Terminal-bench task directory export and import.
+//!
+//! Writes one directory per task containing `task.yaml` (metadata and
+//! verification spec, hidden from the agent), `prompt.md` (the instruction
+//! shown to the agent) and `solution.sh` (the reference solution). Synthetic
+//! and mined SWE tasks are both mapped onto the same `TerminalBenchTask`.
+//! `TaskImporter` reads such directories, including hand-written ones, back
+//! into `SyntheticTask`s for validation and quality scoring.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::agents::task_executor::{
+    AntiMemorizationConfig, AutomatedCheck, DifficultyScoring, HiddenSolution, PartialCreditItem,
+    SyntheticTask, TaskMetadata, VerificationSpec,
+};
+use crate::difficulty::DifficultyLevel;
+use crate::error::ImportError;
+use crate::swe::SweTask;
+
+/// File name of the task metadata.
+pub const TASK_YAML: &str = "task.yaml";
+/// File name of the agent-facing instruction.
+pub const PROMPT_MD: &str = "prompt.md";
+/// File name of the reference solution script.
+pub const SOLUTION_SH: &str = "solution.sh";
+
+/// Heredoc delimiter used when embedding a patch in `solution.sh`.
+const PATCH_DELIMITER: &str = "SWE_FORGE_PATCH";
+
+/// Verification block of a terminal-bench `task.yaml`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TerminalBenchVerification {
+    /// Criteria that define a successful solution.
+    #[serde(default)]
+    pub success_criteria: Vec<String>,
+    /// Automated checks run by the verifier.
+    #[serde(default)]
+    pub automated_checks: Vec<AutomatedCheck>,
+    /// Criteria for awarding partial credit.
+    #[serde(default)]
+    pub partial_credit_criteria: Vec<PartialCreditItem>,
+}
+
+/// Contents of a terminal-bench `task.yaml`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TerminalBenchTask {
+    /// Task identifier, also used as the directory name.
+    #[serde(default)]
+    pub id: String,
+    /// Instruction presented to the agent.
+    #[serde(default)]
+    pub instruction: String,
+    /// Difficulty level.
+    pub difficulty: DifficultyLevel,
+    /// Primary category.
+    pub category: String,
+    /// Searchable tags.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Time budget for the agent, in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_agent_timeout_sec: Option<u32>,
+    /// Verification specification.
+    #[serde(default)]
+    pub verification: TerminalBenchVerification,
+    /// Canary token embedded for contamination detection.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub canary: String,
+}
+
+impl From<&SyntheticTask> for TerminalBenchTask {
+    fn from(task: &SyntheticTask) -> Self {
+        Self {
+            id: task.id.clone(),
+            instruction: task.problem_statement.clone(),
+            difficulty: task.difficulty.level,
+            category: task.metadata.category.clone(),
+            tags: task.metadata.tags.clone(),
+            max_agent_timeout_sec: Some(task.hidden_solution.expected_time_seconds),
+            verification: TerminalBenchVerification {
+                success_criteria: task.verification.success_criteria.clone(),
+                automated_checks: task.verification.automated_checks.clone(),
+                partial_credit_criteria: task.verification.partial_credit_criteria.clone(),
+            },
+            canary: task.anti_memorization.canary_token.clone(),
+        }
+    }
+}
+
+impl From<&SweTask> for TerminalBenchTask {
+    fn from(task: &SweTask) -> Self {
+        let difficulty = match task.meta.get("difficulty").map(|d| d.to_lowercase()) {
+            Some(d) if d == "easy" => DifficultyLevel::Easy,
+            Some(d) if d == "hard" => DifficultyLevel::Hard,
+            _ => DifficultyLevel::Medium,
+        };
+        let automated_checks = task
+            .fail_to_pass
+            .iter()
+            .chain(&task.pass_to_pass)
+            .map(AutomatedCheck::command_succeeds)
+            .collect();
+
+        Self {
+            id: task.id.clone(),
+            instruction: task.prompt.clone(),
+            difficulty,
+            category: task.language.clone(),
+            tags: vec!["swe".to_string(), task.repo.clone()],
+            max_agent_timeout_sec: None,
+            verification: TerminalBenchVerification {
+                success_criteria: Vec::new(),
+                automated_checks,
+                partial_credit_criteria: Vec::new(),
+            },
+            canary: String::new(),
+        }
+    }
+}
+
+/// Writes tasks as terminal-bench directories under an output root.
+#[derive(Debug, Clone)]
+pub struct TerminalBenchExporter {
+    output_dir: PathBuf,
+}
+
+impl TerminalBenchExporter {
+    /// Create an exporter writing task directories under `output_dir`.
+    pub fn new(output_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            output_dir: output_dir.into(),
+        }
+    }
+
+    /// Export a synthetic task; the solution runs its reference commands.
+    pub fn export_synthetic(&self, task: &SyntheticTask) -> anyhow::Result<PathBuf> {
+        let solution = solution_script(&task.hidden_solution.reference_commands);
+        self.write(&TerminalBenchTask::from(task), &solution)
+    }
+
+    /// Export a mined SWE task; the solution applies the PR patch.
+    pub fn export_swe(&self, task: &SweTask) -> anyhow::Result<PathBuf> {
+        let apply = format!(
+            "git apply <<'{delim}'\n{}\n{delim}",
+            task.patch.trim_end_matches('\n'),
+            delim = PATCH_DELIMITER
+        );
+        self.write(&TerminalBenchTask::from(task), &solution_script(&[apply]))
+    }
+
+    fn write(&self, task: &TerminalBenchTask, solution: &str) -> anyhow::Result<PathBuf> {
+        let dir = self.output_dir.join(&task.id);
+        fs::create_dir_all(&dir)
+            .with_context(|| format!("failed to create task directory {}", dir.display()))?;
+
+        let yaml = serde_yaml::to_string(task).context("failed to serialize task.yaml")?;
+        write_file(&dir, TASK_YAML, &yaml)?;
+        write_file(
+            &dir,
+            PROMPT_MD,
+            &format!("{}\n", task.instruction.trim_end()),
+        )?;
+        write_file(&dir, SOLUTION_SH, solution)?;
+        make_executable(&dir.join(SOLUTION_SH))?;
+
+        Ok(dir)
+    }
+}
+
+/// Reads terminal-bench task directories into `SyntheticTask`s.
+///
+/// `task.yaml` is required. The instruction comes from `prompt.md` when
+/// present, otherwise from the `instruction` field; the task id defaults to
+/// the directory name. `solution.sh`, when present, becomes a single reference
+/// command running the whole script under bash, so the task can be checked by
+/// `DockerValidatorAgent`.
+#[derive(Debug, Clone, Default)]
+pub struct TaskImporter;
+
+impl TaskImporter {
+    /// Create a new importer.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Import a single task directory.
+    pub fn import_task(&self, dir: impl AsRef<Path>) -> Result<SyntheticTask, ImportError> {
+        let dir = dir.as_ref();
+        if !dir.is_dir() {
+            return Err(ImportError::NotADirectory(dir.display().to_string()));
+        }
+
+        let yaml_path = dir.join(TASK_YAML);
+        if !yaml_path.is_file() {
+            return Err(ImportError::MissingFile {
+                dir: dir.display().to_string(),
+                file: TASK_YAML.to_string(),
+            });
+        }
+        let mut spec: TerminalBenchTask = serde_yaml::from_str(&fs::read_to_string(&yaml_path)?)
+            .map_err(|e| ImportError::from_yaml(yaml_path.display().to_string(), &e))?;
+
+        if spec.id.trim().is_empty() {
+            spec.id = dir
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+        }
+        if !is_safe_task_id(&spec.id) {
+            return Err(ImportError::InvalidTaskId(spec.id));
+        }
+        let prompt_path = dir.join(PROMPT_MD);
+        if prompt_path.is_file() {
+            spec.instruction = fs::read_to_string(&prompt_path)?;
+        }
+        let instruction = spec.instruction.trim();
+        if instruction.is_empty() {
+            return Err(ImportError::MissingField {
+                task: spec.id,
+                field: format!("instruction ({} or task.yaml `instruction`)", PROMPT_MD),
+            });
+        }
+        if spec.category.trim().is_empty() {
+            return Err(ImportError::MissingField {
+                task: spec.id,
+                field: "category".to_string(),
+            });
+        }
+
+        let solution_path = dir.join(SOLUTION_SH);
+        let reference_commands = if solution_path.is_file() {
+            solution_command(&fs::read_to_string(&solution_path)?)
+                .into_iter()
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let mut hidden_solution =
+            HiddenSolution::new(format!("Reference solution from {}", SOLUTION_SH))
+                .with_reference_commands(reference_commands);
+        if let Some(seconds) = spec.max_agent_timeout_sec {
+            hidden_solution = hidden_solution.with_expected_time_seconds(seconds);
+        }
+
+        let mut task = SyntheticTask::new(
+            instruction,
+            hidden_solution,
+            VerificationSpec::new()
+                .with_success_criteria(spec.verification.success_criteria)
+                .with_automated_checks(spec.verification.automated_checks)
+                .with_partial_credit(spec.verification.partial_credit_criteria),
+            DifficultyScoring::new(spec.difficulty),
+            TaskMetadata::new(spec.category, format!("terminal-bench:{}", spec.id))
+                .with_tags(spec.tags),
+        )
+        .with_anti_memorization(AntiMemorizationConfig::new(spec.canary));
+        task.id = spec.id;
+        Ok(task)
+    }
+
+    /// Import every task directory directly under `root`, in name order.
+    pub fn import_all(&self, root: impl AsRef<Path>) -> Result<Vec<SyntheticTask>, ImportError> {
+        let root = root.as_ref();
+        if !root.is_dir() {
+            return Err(ImportError::NotADirectory(root.display().to_string()));
+        }
+        let mut dirs = Vec::new();
+        for entry in fs::read_dir(root)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                dirs.push(path);
+            }
+        }
+        dirs.sort();
+        dirs.iter().map(|dir| self.import_task(dir)).collect()
+    }
+}
+
+/// Whether a task id is safe to use as a single directory name.
+fn is_safe_task_id(id: &str) -> bool {
+    !id.contains('/') && !id.contains('\\') && !id.contains("..")
+}
+
+/// Wrap a solution script as one reference command.
+///
+/// The script runs as a whole under `bash -euo pipefail` so control flow,
+/// heredocs and shell state behave as they would when executing the file.
+/// Returns `None` when the script has nothing but comments and blank lines.
+fn solution_command(script: &str) -> Option<String> {
+    let has_body = script.lines().any(|line| {
+        let trimmed = line.trim();
+        !trimmed.is_empty() && !trimmed.starts_with('#')
+    });
+    has_body.then(|| {
+        format!(
+            "bash -euo pipefail -c '{}'",
+            script.trim_end().replace('\'', r"'\''")
+        )
+    })
+}
+
+/// Render a bash script running `commands` in order, stopping on failure.
+fn solution_script<S: AsRef<str>>(commands: &[S]) -> String {
+    let mut script = String::from("#!/bin/bash\nset -euo pipefail\n\n");
+    for command in commands {
+        script.push_str(command.as_ref().trim_end());
+        script.push('\n');
+    }
+    script
+}
+
+fn write_file(dir: &Path, name: &str, content: &str) -> anyhow::Result<()> {
+    let path = dir.join(name);
+    fs::write(&path, content).with_context(|| format!("failed to write {}", path.display()))
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> anyhow::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    fs::set_permissions(path, fs::Permissions::from_mode(0o755))
+        .with_context(|| format!("failed to set permissions on {}", path.display()))
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> anyhow::Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::task_executor::{
+        DifficultyScoring, HiddenSolution, TaskMetadata, VerificationSpec,
+    };
+
+    fn synthetic_task() -> SyntheticTask {
+        let mut task = SyntheticTask::new(
+            "Count the ERROR lines in /var/log/app.log and write the total to /output/count.txt.",
+            HiddenSolution::new("grep and count")
+                .with_reference_commands(["grep -c ERROR /var/log/app.log > /output/count.txt"])
+                .with_expected_time_seconds(420),
+            VerificationSpec::new()
+                .with_success_criteria(["count.txt contains the number of ERROR lines"])
+                .with_automated_checks([AutomatedCheck::file_exists("count.txt")]),
+            DifficultyScoring::new(DifficultyLevel::Easy),
+            TaskMetadata::new("log-analysis", "idea-1").with_tags(["logs", "grep"]),
+        );
+        task.id = "count-errors".to_string();
+        task
+    }
+
+    #[test]
+    fn test_export_synthetic_writes_three_files() {
+        let out = tempfile::tempdir().unwrap();
+        let dir = TerminalBenchExporter::new(out.path())
+            .export_synthetic(&synthetic_task())
+            .unwrap();
+
+        assert_eq!(dir, out.path().join("count-errors"));
+        let prompt = fs::read_to_string(dir.join(PROMPT_MD)).unwrap();
+        assert!(prompt.starts_with("Count the ERROR lines"));
+
+        let solution = fs::read_to_string(dir.join(SOLUTION_SH)).unwrap();
+        assert!(solution.starts_with("#!/bin/bash\nset -euo pipefail\n"));
+        assert!(solution.contains("grep -c ERROR /var/log/app.log > /output/count.txt\n"));
+
+        let yaml = fs::read_to_string(dir.join(TASK_YAML)).unwrap();
+        let parsed: TerminalBenchTask = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(parsed.id, "count-errors");
+        assert_eq!(parsed.difficulty, DifficultyLevel::Easy);
+        assert_eq!(parsed.category, "log-analysis");
+        assert_eq!(parsed.tags, vec!["logs", "grep"]);
+        assert_eq!(parsed.max_agent_timeout_sec, Some(420));
+        assert_eq!(parsed.verification.automated_checks.len(), 1);
+        assert!(!yaml.contains("grep and count"));
+    }
+
+    #[test]
+    fn test_export_swe_embeds_patch() {
+        let mut task = SweTask::new("acme-widgets-42", "acme/widgets");
+        task.language = "python".to_string();
+        task.prompt = "Fix the off-by-one in pagination.".to_string();
+        task.patch = "diff --git a/app.py b/app.py\n-    return items[1:]\n+    return items\n \n"
+            .to_string();
+        task.fail_to_pass = vec!["pytest tests/test_pages.py".to_string()];
+        task.meta
+            .insert("difficulty".to_string(), "hard".to_string());
+
+        let out = tempfile::tempdir().unwrap();
+        let dir = TerminalBenchExporter::new(out.path())
+            .export_swe(&task)
+            .unwrap();
+
+        let solution = fs::read_to_string(dir.join(SOLUTION_SH)).unwrap();
+        assert!(solution.contains("git apply <<'SWE_FORGE_PATCH'\ndiff --git a/app.py b/app.py"));
+        // Trailing blank context lines are significant to `git apply`.
+        assert!(solution.ends_with("+    return items\n \nSWE_FORGE_PATCH\n"));
+        assert_eq!(
+            fs::read_to_string(dir.join(PROMPT_MD)).unwrap(),
+            "Fix the off-by-one in pagination.\n"
+        );
+
+        let parsed: TerminalBenchTask =
+            serde_yaml::from_str(&fs::read_to_string(dir.join(TASK_YAML)).unwrap()).unwrap();
+        assert_eq!(parsed.difficulty, DifficultyLevel::Hard);
+        assert_eq!(
+            parsed.verification.automated_checks[0].target,
+            "pytest tests/test_pages.py"
+        );
+    }
+
+    #[test]
+    fn test_import_fixture_directory() {
+        let root = tempfile::tempdir().unwrap();
+        let dir = root.path().join("fix-permissions");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join(TASK_YAML),
+            r#"
+instruction: "Make every script in /app/bin executable."
+difficulty: medium
+category: system-administration
+tags: [chmod, files]
+max_agent_timeout_sec: 600
+verification:
+  success_criteria:
+    - all scripts are executable
+  automated_checks:
+    - check_type: command_succeeds
+      target: test -x /app/bin/run.sh
+      expected: "0"
+"#,
+        )
+        .unwrap();
+        fs::write(
+            dir.join(SOLUTION_SH),
+            "#!/bin/bash\nset -euo pipefail\n\n# fix modes\nchmod +x \\\n  /app/bin/*.sh\nls -l /app/bin\n",
+        )
+        .unwrap();
+
+        let task = TaskImporter::new().import_task(&dir).unwrap();
+        assert_eq!(task.id, "fix-permissions");
+        assert_eq!(
+            task.problem_statement,
+            "Make every script in /app/bin executable."
+        );
+        assert_eq!(task.difficulty.level, DifficultyLevel::Medium);
+        assert_eq!(task.metadata.category, "system-administration");
+        assert_eq!(task.metadata.tags, vec!["chmod", "files"]);
+        assert_eq!(
+            task.metadata.source_idea_id,
+            "terminal-bench:fix-permissions"
+        );
+        assert_eq!(task.hidden_solution.expected_time_seconds, 600);
+        assert_eq!(task.hidden_solution.reference_commands.len(), 1);
+        let command = &task.hidden_solution.reference_commands[0];
+        assert!(command.starts_with("bash -euo pipefail -c '#!/bin/bash\n"));
+        assert!(command.ends_with("chmod +x \\\n  /app/bin/*.sh\nls -l /app/bin'"));
+        assert_eq!(task.verification.success_criteria.len(), 1);
+        assert_eq!(
+            task.verification.automated_checks[0].target,
+            "test -x /app/bin/run.sh"
+        );
+        assert!(!task.has_canary());
+    }
+
+    #[test]
+    fn test_import_roundtrips_exported_task() {
+        let out = tempfile::tempdir().unwrap();
+        let mut original = synthetic_task();
+        original.anti_memorization = AntiMemorizationConfig::new("CANARY-123");
+        TerminalBenchExporter::new(out.path())
+            .export_synthetic(&original)
+            .unwrap();
+
+        let imported = TaskImporter::new().import_all(out.path()).unwrap();
+        assert_eq!(imported.len(), 1);
+        let task = &imported[0];
+        assert_eq!(task.id, original.id);
+        assert_eq!(task.problem_statement, original.problem_statement);
+        assert_eq!(task.hidden_solution.reference_commands.len(), 1);
+        assert!(task.hidden_solution.reference_commands[0]
+            .contains("grep -c ERROR /var/log/app.log > /output/count.txt"));
+        assert_eq!(task.anti_memorization.canary_token, "CANARY-123");
+    }
+
+    #[test]
+    fn test_import_malformed_directories() {
+        let root = tempfile::tempdir().unwrap();
+        let importer = TaskImporter::new();
+
+        let err = importer.import_task(root.path()).unwrap_err();
+        assert!(matches!(err, ImportError::MissingFile { ref file, .. } if file == TASK_YAML));
+
+        fs::write(root.path().join(TASK_YAML), "difficulty: [unclosed").unwrap();
+        let err = importer.import_task(root.path()).unwrap_err();
+        assert!(matches!(err, ImportError::Parse { .. }), "{err}");
+
+        fs::write(
+            root.path().join(TASK_YAML),
+            "difficulty: easy\ncategory: misc\n",
+        )
+        .unwrap();
+        let err = importer.import_task(root.path()).unwrap_err();
+        assert!(err.to_string().contains("has no instruction"), "{err}");
+
+        let err = importer
+            .import_task(root.path().join(TASK_YAML))
+            .unwrap_err();
+        assert!(matches!(err, ImportError::NotADirectory(_)));
+
+        for id in ["../escape", "nested/task", ".."] {
+            fs::write(
+                root.path().join(TASK_YAML),
+                format!("id: \"{id}\"\ninstruction: hi\ndifficulty: easy\ncategory: misc\n"),
+            )
+            .unwrap();
+            let err = importer.import_task(root.path()).unwrap_err();
+            assert!(matches!(err, ImportError::InvalidTaskId(_)), "{err}");
+        }
+    }
+
+    #[test]
+    fn test_solution_command_quotes_script() {
+        let command = solution_command("#!/bin/bash\necho 'done' > /tmp/out\n").unwrap();
+        assert_eq!(
+            command,
+            r"bash -euo pipefail -c '#!/bin/bash
+echo '\''done'\'' > /tmp/out'"
+        );
+        assert!(solution_command("#!/bin/bash\n\n# nothing yet\n").is_none());
+    }
+}
@@ -10,6 +10,7 @@ use std::env;
 use std::time::Duration;
 
 use super::cache::{CachedMessage, PromptCache};
+use super::schema::{unsupported_schema_keywords, validate_json_schema};
 use crate::error::LlmError;
 
 /// A message in a conversation with an LLM.
@@ -314,6 +315,8 @@ pub struct LiteLlmClient {
     default_model: String,
     /// HTTP client for making API requests.
     http_client: Client,
+    /// Whether to validate responses against a requested JSON schema.
+    validate_schema: bool,
 }
 
 impl LiteLlmClient {
@@ -343,6 +346,7 @@ impl LiteLlmClient {
                 .map_err(|e| {
                     LlmError::RequestFailed(format!("Failed to build HTTP client: {e}"))
                 })?,
+            validate_schema: false,
         })
     }
 
@@ -372,6 +376,7 @@ impl LiteLlmClient {
                 .map_err(|e| {
                     LlmError::RequestFailed(format!("Failed to build HTTP client: {e}"))
                 })?,
+            validate_schema: false,
         })
     }
 
@@ -401,9 +406,23 @@ impl LiteLlmClient {
                 .map_err(|e| {
                     LlmError::RequestFailed(format!("Failed to build HTTP client: {e}"))
                 })?,
+            validate_schema: false,
         })
     }
 
+    /// Validate responses against the request's `JsonSchemaSpec`.
+    ///
+    /// When enabled, a response to a `ResponseFormat::JsonSchema` request whose
+    /// content does not conform fails with `LlmError::SchemaViolation`, so the
+    /// caller can retry instead of hitting a cryptic downstream parse error.
+    /// Only the keyword subset documented in [`crate::llm::schema`] is checked;
+    /// requests whose schema uses other keywords fail up front with
+    /// `LlmError::UnsupportedSchema`.
+    pub fn with_schema_validation(mut self, enabled: bool) -> Self {
+        self.validate_schema = enabled;
+        self
+    }
+
     /// Get the API base URL.
     pub fn api_base(&self) -> &str {
         &self.api_base
@@ -613,6 +632,17 @@ impl LlmProvider for LiteLlmClient {
             None => None,
         };
 
+        let schema = match (&request.response_format, self.validate_schema) {
+            (Some(ResponseFormat::JsonSchema { json_schema }), true) => {
+                let keywords = unsupported_schema_keywords(&json_schema.schema);
+                if !keywords.is_empty() {
+                    return Err(LlmError::UnsupportedSchema { keywords });
+                }
+                Some(json_schema.schema.clone())
+            }
+            _ => None,
+        };
+
         let api_request = ApiRequest {
             model: model.clone(),
             messages: request.messages,
@@ -648,6 +678,8 @@ impl LlmProvider for LiteLlmClient {
         if !status.is_success() {
             let status_code = status.as_u16();
 
+            let retry_after = retry_after_from_headers(http_response.headers());
+
             // Try to parse error response body
             let error_text = http_response
                 .text()
@@ -658,7 +690,10 @@ impl LlmProvider for LiteLlmClient {
             if let Ok(error_response) = serde_json::from_str::<ApiErrorResponse>(&error_text) {
                 // Handle rate limiting specifically
                 if status_code == 429 {
-                    return Err(LlmError::RateLimited(error_response.error.message));
+                    return Err(LlmError::RateLimited {
+                        message: error_response.error.message,
+                        retry_after,
+                    });
                 }
 
                 return Err(LlmError::ApiError {
@@ -732,7 +767,7 @@ impl LlmProvider for LiteLlmClient {
             })
             .collect();
 
-        Ok(GenerationResponse {
+        let response = GenerationResponse {
             id: api_response.id,
             model: api_response.model,
             choices,
@@ -741,7 +776,44 @@ impl LlmProvider for LiteLlmClient {
                 completion_tokens: api_response.usage.completion_tokens,
                 total_tokens: api_response.usage.total_tokens,
             },
-        })
+        };
+
+        if let Some(schema) = schema {
+            check_response_schema(&response, &schema)?;
+        }
+
+        Ok(response)
+    }
+}
+
+/// Parse a `Retry-After` header given in seconds.
+pub(crate) fn retry_after_from_headers(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// Check that the first choice's content is JSON conforming to `schema`.
+fn check_response_schema(
+    response: &GenerationResponse,
+    schema: &serde_json::Value,
+) -> Result<(), LlmError> {
+    let content = response.first_content().unwrap_or_default();
+    let value: serde_json::Value =
+        serde_json::from_str(content).map_err(|e| LlmError::SchemaViolation {
+            errors: vec![format!("$: response is not valid JSON: {}", e)],
+        })?;
+
+    let errors = validate_json_schema(&value, schema);
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(LlmError::SchemaViolation { errors })
     }
 }
 
@@ -1000,6 +1072,153 @@ mod tests {
         assert!(matches!(err, LlmError::RequestFailed(_)));
     }
 
+    /// Serve a single canned chat completion on a local port.
+    async fn serve_completion(content: &str) -> String {
+        let body = serde_json::json!({
+            "id": "resp-1",
+            "model": "gpt-4",
+            "choices": [{
+                "index": 0,
+                "message": {"role": "assistant", "content": content},
+                "finish_reason": "stop"
+            }],
+            "usage": {"prompt_tokens": 1, "completion_tokens": 1, "total_tokens": 2}
+        })
+        .to_string();
+        serve_response("200 OK", "", body).await
+    }
+
+    /// Serve a single canned HTTP response on a local port.
+    async fn serve_response(status: &str, extra_headers: &str, body: String) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let status = status.to_string();
+        let extra_headers = extra_headers.to_string();
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut received = Vec::new();
+            let mut buf = [0u8; 4096];
+            loop {
+                let n = socket.read(&mut buf).await.unwrap();
+                received.extend_from_slice(&buf[..n]);
+                let text = String::from_utf8_lossy(&received).to_string();
+                if let Some(header_end) = text.find("\r\n\r\n") {
+                    let length = text[..header_end]
+                        .lines()
+                        .find_map(|l| {
+                            l.to_lowercase()
+                                .strip_prefix("content-length:")
+                                .map(|v| v.trim().parse::<usize>().unwrap())
+                        })
+                        .unwrap_or(0);
+                    if received.len() >= header_end + 4 + length {
+                        break;
+                    }
+                }
+                if n == 0 {
+                    break;
+                }
+            }
+            let reply = format!(
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                extra_headers,
+                body.len(),
+                body
+            );
+            socket.write_all(reply.as_bytes()).await.unwrap();
+        });
+
+        format!("http://{}", addr)
+    }
+
+    fn schema_request() -> GenerationRequest {
+        GenerationRequest::new("gpt-4", vec![Message::user("test")]).with_response_format(
+            ResponseFormat::JsonSchema {
+                json_schema: JsonSchemaSpec {
+                    name: "task".to_string(),
+                    strict: true,
+                    schema: serde_json::json!({
+                        "type": "object",
+                        "properties": {
+                            "title": {"type": "string"},
+                            "difficulty": {"type": "string"}
+                        },
+                        "required": ["title", "difficulty"]
+                    }),
+                },
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn test_schema_violation_on_missing_required_field() {
+        let api_base = serve_completion(r#"{"title": "Fix bug"}"#).await;
+        let client = LiteLlmClient::new(api_base, None, "gpt-4".to_string())
+            .unwrap()
+            .with_schema_validation(true);
+
+        let err = client.generate(schema_request()).await.unwrap_err();
+
+        match err {
+            LlmError::SchemaViolation { errors } => {
+                assert_eq!(errors.len(), 1);
+                assert!(errors[0].contains("difficulty"));
+            }
+            other => panic!("expected SchemaViolation, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_schema_keywords_rejected_before_request() {
+        // Nothing listens here: the request must fail before any HTTP call.
+        let client =
+            LiteLlmClient::new("http://127.0.0.1:9".to_string(), None, "gpt-4".to_string())
+                .unwrap()
+                .with_schema_validation(true);
+        let mut request = schema_request();
+        if let Some(ResponseFormat::JsonSchema { json_schema }) = request.response_format.as_mut() {
+            json_schema.schema["properties"]["title"]["pattern"] = serde_json::json!("^F");
+        }
+
+        match client.generate(request).await.unwrap_err() {
+            LlmError::UnsupportedSchema { keywords } => {
+                assert_eq!(keywords, vec!["$.title: pattern".to_string()]);
+            }
+            other => panic!("expected UnsupportedSchema, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_carries_retry_after() {
+        let body = r#"{"error": {"message": "slow down"}}"#.to_string();
+        let api_base = serve_response("429 Too Many Requests", "Retry-After: 7\r\n", body).await;
+        let client = LiteLlmClient::new(api_base, None, "gpt-4".to_string()).unwrap();
+
+        let request = GenerationRequest::new("gpt-4", vec![Message::user("test")]);
+        match client.generate(request).await.unwrap_err() {
+            LlmError::RateLimited {
+                message,
+                retry_after,
+            } => {
+                assert_eq!(message, "slow down");
+                assert_eq!(retry_after, Some(Duration::from_secs(7)));
+            }
+            other => panic!("expected RateLimited, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_schema_validation_disabled_by_default() {
+        let api_base = serve_completion(r#"{"title": "Fix bug"}"#).await;
+        let client = LiteLlmClient::new(api_base, None, "gpt-4".to_string()).unwrap();
+
+        let response = client.generate(schema_request()).await.unwrap();
+        assert_eq!(response.first_content(), Some(r#"{"title": "Fix bug"}"#));
+    }
+
     #[test]
     fn test_api_request_serialization() {
         let request = ApiRequest {
@@ -0,0 +1,225 @@
+//! Scriptable in-process LLM provider for tests.
+//!
+//! `MockLlmProvider` implements [`LlmProvider`] without any network access.
+//! Responses are either matched by a substring of the prompt or consumed in
+//! sequence, and every received request is recorded for later assertions.
+//!
+//! ```ignore
+//! use swe_forge::llm::{GenerationRequest, LlmProvider, Message, MockLlmProvider};
+//!
+//! let llm = MockLlmProvider::new()
+//!     .with_keyed_response("classify", r#"{"category":"bugfix"}"#)
+//!     .with_response("first")
+//!     .with_response("second");
+//!
+//! let response = llm
+//!     .generate(GenerationRequest::new("m", vec![Message::user("hello")]))
+//!     .await?;
+//! assert_eq!(response.first_content(), Some("first"));
+//! assert_eq!(llm.request_count().await, 1);
+//! ```
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+
+use crate::error::LlmError;
+use crate::llm::{Choice, GenerationRequest, GenerationResponse, LlmProvider, Message, Usage};
+
+/// Model name reported in mock responses.
+const MOCK_MODEL: &str = "mock-model";
+
+/// In-process LLM provider returning scripted responses.
+///
+/// Resolution order for each request:
+/// 1. The first keyed response whose key appears in any message content.
+/// 2. The next queued response (content or error), consumed once.
+/// 3. The fallback response, if configured.
+///
+/// If none apply, the request fails with [`LlmError::RequestFailed`].
+#[derive(Default)]
+pub struct MockLlmProvider {
+    /// Reusable responses matched by prompt substring.
+    keyed: Vec<(String, String)>,
+    /// Responses consumed in order.
+    queue: Mutex<VecDeque<Result<String, LlmError>>>,
+    /// Response returned once keyed and queued responses are exhausted.
+    fallback: Option<String>,
+    /// Artificial delay applied before every response.
+    latency: Option<Duration>,
+    /// Every request received, in order.
+    requests: Mutex<Vec<GenerationRequest>>,
+}
+
+impl MockLlmProvider {
+    /// Create a mock with no scripted responses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue a response returned once, in order.
+    pub fn with_response(mut self, content: impl Into<String>) -> Self {
+        self.queue.get_mut().push_back(Ok(content.into()));
+        self
+    }
+
+    /// Queue an error returned once, in order.
+    pub fn with_error(mut self, error: LlmError) -> Self {
+        self.queue.get_mut().push_back(Err(error));
+        self
+    }
+
+    /// Return `content` whenever any message contains `key`.
+    pub fn with_keyed_response(
+        mut self,
+        key: impl Into<String>,
+        content: impl Into<String>,
+    ) -> Self {
+        self.keyed.push((key.into(), content.into()));
+        self
+    }
+
+    /// Return `content` when no keyed or queued response applies.
+    pub fn with_fallback(mut self, content: impl Into<String>) -> Self {
+        self.fallback = Some(content.into());
+        self
+    }
+
+    /// Delay every response by `latency`.
+    pub fn with_latency(mut self, latency: Duration) -> Self {
+        self.latency = Some(latency);
+        self
+    }
+
+    /// All requests received so far, in order.
+    pub async fn requests(&self) -> Vec<GenerationRequest> {
+        self.requests.lock().await.clone()
+    }
+
+    /// Number of requests received so far.
+    pub async fn request_count(&self) -> usize {
+        self.requests.lock().await.len()
+    }
+
+    /// Resolve the scripted outcome for a request.
+    async fn resolve(&self, request: &GenerationRequest) -> Result<String, LlmError> {
+        let keyed = self.keyed.iter().find(|(key, _)| {
+            request
+                .messages
+                .iter()
+                .any(|message| message.content.contains(key.as_str()))
+        });
+        if let Some((_, content)) = keyed {
+            return Ok(content.clone());
+        }
+
+        if let Some(next) = self.queue.lock().await.pop_front() {
+            return next;
+        }
+
+        self.fallback.clone().ok_or_else(|| {
+            LlmError::RequestFailed("MockLlmProvider has no scripted response left".to_string())
+        })
+    }
+}
+
+#[async_trait]
+impl LlmProvider for MockLlmProvider {
+    async fn generate(&self, request: GenerationRequest) -> Result<GenerationResponse, LlmError> {
+        if let Some(latency) = self.latency {
+            tokio::time::sleep(latency).await;
+        }
+
+        let outcome = self.resolve(&request).await;
+        let model = if request.model.is_empty() {
+            MOCK_MODEL.to_string()
+        } else {
+            request.model.clone()
+        };
+        self.requests.lock().await.push(request);
+
+        let content = outcome?;
+        Ok(GenerationResponse {
+            id: "mock-response".to_string(),
+            model,
+            choices: vec![Choice {
+                index: 0,
+                message: Message::assistant(content),
+                finish_reason: "stop".to_string(),
+            }],
+            usage: Usage {
+                prompt_tokens: 0,
+                completion_tokens: 0,
+                total_tokens: 0,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::{TaskIdea, TaskValidatorAgent};
+
+    fn request(prompt: &str) -> GenerationRequest {
+        GenerationRequest::new("test-model", vec![Message::user(prompt)])
+    }
+
+    #[tokio::test]
+    async fn test_sequence_keyed_and_errors() {
+        let llm = MockLlmProvider::new()
+            .with_keyed_response("classify", "bugfix")
+            .with_response("first")
+            .with_error(LlmError::RateLimited {
+                message: "slow down".to_string(),
+                retry_after: None,
+            });
+
+        let keyed = llm.generate(request("please classify this")).await.unwrap();
+        assert_eq!(keyed.first_content(), Some("bugfix"));
+
+        let first = llm.generate(request("hello")).await.unwrap();
+        assert_eq!(first.first_content(), Some("first"));
+
+        let err = llm.generate(request("hello again")).await.unwrap_err();
+        assert!(matches!(err, LlmError::RateLimited { .. }));
+
+        let exhausted = llm.generate(request("anything")).await.unwrap_err();
+        assert!(matches!(exhausted, LlmError::RequestFailed(_)));
+
+        let requests = llm.requests().await;
+        assert_eq!(requests.len(), 4);
+        assert_eq!(requests[1].messages[0].content, "hello");
+    }
+
+    #[tokio::test]
+    async fn test_fallback_and_latency() {
+        let llm = MockLlmProvider::new()
+            .with_fallback("ok")
+            .with_latency(Duration::from_millis(20));
+
+        let start = std::time::Instant::now();
+        let response = llm.generate(request("x")).await.unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(20));
+        assert_eq!(response.first_content(), Some("ok"));
+        assert_eq!(llm.request_count().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_drives_agent_through_llm_provider() {
+        let llm = std::sync::Arc::new(MockLlmProvider::new().with_fallback("not json"));
+        let agent = TaskValidatorAgent::with_defaults(llm.clone());
+
+        let idea = TaskIdea::new(
+            "Find failing service",
+            "Find the failing service and report its exit code.",
+            "debugging",
+            vec!["systemd".to_string()],
+        );
+        let _ = agent.validate_task(&idea).await;
+
+        assert!(llm.request_count().await >= 1);
+    }
+}
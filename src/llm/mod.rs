@@ -61,8 +61,11 @@
 pub mod cache;
 pub mod cost;
 pub mod litellm;
+pub mod mock;
 pub mod providers;
 pub mod router;
+pub mod schema;
+pub mod tokens;
 
 pub use cache::{
     create_shared_cache, create_shared_cache_with_config, CacheConfig, CacheStats, CachedMessage,
@@ -76,7 +79,10 @@ pub use litellm::{
 
 // Re-export key types from submodules for convenience
 pub use cost::{CostReport, CostTracker, UsageRecord};
+pub use mock::MockLlmProvider;
 pub use providers::OpenRouterProvider;
 pub use router::{
     LlmRouter, ModelCapabilities, MultiModelRouter, RouterError, RoutingStrategy, TaskHint,
 };
+pub use schema::{unsupported_schema_keywords, validate_json_schema};
+pub use tokens::{count_tokens, TokenizerFamily};
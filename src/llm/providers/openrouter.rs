@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
 use crate::error::LlmError;
+use crate::llm::litellm::retry_after_from_headers;
 #[cfg(test)]
 use crate::llm::ResponseFormat;
 use crate::llm::{
@@ -31,6 +32,9 @@ const BASE_RETRY_DELAY_MS: u64 = 1000;
 /// Request timeout in seconds (increased for large code generation).
 const REQUEST_TIMEOUT_SECS: u64 = 300;
 
+/// Connect timeout in seconds for establishing the TCP/TLS connection.
+const CONNECT_TIMEOUT_SECS: u64 = 10;
+
 /// OpenRouter provider for LLM requests.
 ///
 /// This provider implements the `LlmProvider` trait and routes requests
@@ -56,10 +60,10 @@ impl OpenRouterProvider {
     /// * `api_key` - OpenRouter API key for authentication
     pub fn new(api_key: String) -> Self {
         Self {
-            client: Client::builder()
-                .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
-                .build()
-                .expect("Failed to build HTTP client - system TLS configuration error"),
+            client: build_client(
+                Duration::from_secs(CONNECT_TIMEOUT_SECS),
+                Duration::from_secs(REQUEST_TIMEOUT_SECS),
+            ),
             api_key,
             base_url: OPENROUTER_BASE_URL.to_string(),
             default_model: DEFAULT_MODEL.to_string(),
@@ -74,10 +78,10 @@ impl OpenRouterProvider {
     /// * `model` - Default model identifier (e.g., "anthropic/claude-3-opus")
     pub fn with_model(api_key: String, model: String) -> Self {
         Self {
-            client: Client::builder()
-                .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
-                .build()
-                .expect("Failed to build HTTP client - system TLS configuration error"),
+            client: build_client(
+                Duration::from_secs(CONNECT_TIMEOUT_SECS),
+                Duration::from_secs(REQUEST_TIMEOUT_SECS),
+            ),
             api_key,
             base_url: OPENROUTER_BASE_URL.to_string(),
             default_model: model,
@@ -113,16 +117,30 @@ impl OpenRouterProvider {
         }
 
         Self {
-            client: Client::builder()
-                .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
-                .build()
-                .expect("Failed to build HTTP client - system TLS configuration error"),
+            client: build_client(
+                Duration::from_secs(CONNECT_TIMEOUT_SECS),
+                Duration::from_secs(REQUEST_TIMEOUT_SECS),
+            ),
             api_key,
             base_url,
             default_model: model,
         }
     }
 
+    /// Configure the connect and overall request timeouts.
+    ///
+    /// Defaults are 10s to connect and 300s per request. Requests exceeding
+    /// either limit fail with a retryable [`LlmError::Timeout`].
+    ///
+    /// # Arguments
+    ///
+    /// * `connect` - Maximum time to establish the connection
+    /// * `request` - Maximum time for the whole request, including the response body
+    pub fn with_timeouts(mut self, connect: Duration, request: Duration) -> Self {
+        self.client = build_client(connect, request);
+        self
+    }
+
     /// Get the API key (for debugging, returns masked value).
     pub fn api_key_masked(&self) -> String {
         if self.api_key.len() <= 8 {
@@ -207,12 +225,13 @@ impl OpenRouterProvider {
             .json(request)
             .send()
             .await
-            .map_err(|e| LlmError::RequestFailed(e.to_string()))?;
+            .map_err(map_reqwest_error)?;
 
         let status = http_response.status();
 
         if !status.is_success() {
             let status_code = status.as_u16();
+            let retry_after = retry_after_from_headers(http_response.headers());
             let error_text = http_response
                 .text()
                 .await
@@ -221,7 +240,10 @@ impl OpenRouterProvider {
             // Try to parse structured error response
             if let Ok(error_response) = serde_json::from_str::<ApiErrorResponse>(&error_text) {
                 if status_code == 429 {
-                    return Err(LlmError::RateLimited(error_response.error.message));
+                    return Err(LlmError::RateLimited {
+                        message: error_response.error.message,
+                        retry_after,
+                    });
                 }
                 return Err(LlmError::ApiError {
                     code: status_code,
@@ -235,10 +257,13 @@ impl OpenRouterProvider {
             });
         }
 
-        let api_response: ApiResponse = http_response
-            .json()
-            .await
-            .map_err(|e| LlmError::ParseError(format!("Failed to parse API response: {}", e)))?;
+        let api_response: ApiResponse = http_response.json().await.map_err(|e| {
+            if e.is_timeout() {
+                LlmError::Timeout(e.to_string())
+            } else {
+                LlmError::ParseError(format!("Failed to parse API response: {}", e))
+            }
+        })?;
 
         // Convert to GenerationResponse
         let choices = api_response
@@ -309,9 +334,28 @@ impl OpenRouterProvider {
     }
 }
 
+/// Build the HTTP client with the given connect and request timeouts.
+fn build_client(connect: Duration, request: Duration) -> Client {
+    Client::builder()
+        .connect_timeout(connect)
+        .timeout(request)
+        .build()
+        .expect("Failed to build HTTP client - system TLS configuration error")
+}
+
+/// Convert a transport error, distinguishing timeouts from other failures.
+fn map_reqwest_error(error: reqwest::Error) -> LlmError {
+    if error.is_timeout() {
+        LlmError::Timeout(error.to_string())
+    } else {
+        LlmError::RequestFailed(error.to_string())
+    }
+}
+
 /// Check if an error is transient and should be retried.
 fn is_transient_error(error: &LlmError) -> bool {
     match error {
+        LlmError::Timeout(_) => true,
         LlmError::RequestFailed(msg) => {
             // Network errors, timeouts, connection issues
             msg.contains("timeout")
@@ -319,7 +363,7 @@ fn is_transient_error(error: &LlmError) -> bool {
                 || msg.contains("temporarily")
                 || msg.contains("Connection refused")
         }
-        LlmError::RateLimited(_) => true,
+        LlmError::RateLimited { .. } => true,
         LlmError::ApiError { code, .. } => {
             // Server errors (5xx) and rate limits are transient
             *code >= 500 || *code == 429
@@ -615,7 +659,10 @@ mod tests {
 
     #[test]
     fn test_is_transient_error_rate_limited() {
-        let error = LlmError::RateLimited("Too many requests".to_string());
+        let error = LlmError::RateLimited {
+            message: "Too many requests".to_string(),
+            retry_after: None,
+        };
         assert!(is_transient_error(&error));
     }
 
@@ -671,6 +718,51 @@ mod tests {
         assert!(matches!(err, LlmError::RequestFailed(_)));
     }
 
+    #[test]
+    fn test_is_transient_error_timeout_variant() {
+        let error = LlmError::Timeout("operation timed out".to_string());
+        assert!(is_transient_error(&error));
+    }
+
+    #[tokio::test]
+    async fn test_request_timeout_maps_to_timeout_error() {
+        // Mock server that accepts connections but never responds in time.
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (_socket, _) = listener.accept().await.unwrap();
+            tokio::time::sleep(Duration::from_secs(5)).await;
+        });
+
+        let provider = OpenRouterProvider::with_custom_url(
+            "test-key".to_string(),
+            format!("http://{}", addr),
+            "test-model".to_string(),
+        )
+        .with_timeouts(Duration::from_secs(1), Duration::from_millis(200));
+
+        let api_request = ApiRequest {
+            model: "test-model".to_string(),
+            messages: vec![Message::user("Hello")],
+            temperature: None,
+            max_tokens: None,
+            top_p: None,
+            reasoning: None,
+            response_format: None,
+            tools: None,
+            tool_choice: None,
+        };
+        let url = format!("{}/chat/completions", provider.base_url());
+        let err = provider
+            .execute_request(&url, &api_request)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, LlmError::Timeout(_)));
+        assert!(is_transient_error(&err));
+        server.abort();
+    }
+
     #[test]
     fn test_api_request_serialization() {
         let request = ApiRequest {
@@ -0,0 +1,275 @@
+//! Lightweight JSON Schema validation for structured LLM output.
+//!
+//! Supports the subset of JSON Schema used by `JsonSchemaSpec` in this crate:
+//! `type` (single or list), `properties`, `required`, `additionalProperties: false`,
+//! `items`, `enum`, `minItems`/`maxItems`, and `minimum`/`maximum`, plus the
+//! annotation keywords `title`, `description`, `default`, `examples`,
+//! `$schema` and `$comment`. Any other keyword would be silently unchecked, so
+//! [`unsupported_schema_keywords`] lets callers reject such schemas up front.
+
+use serde_json::Value;
+
+/// Keywords enforced by [`validate_json_schema`] or safe to ignore (annotations).
+const SUPPORTED_KEYWORDS: &[&str] = &[
+    "type",
+    "properties",
+    "required",
+    "additionalProperties",
+    "items",
+    "enum",
+    "minItems",
+    "maxItems",
+    "minimum",
+    "maximum",
+    "title",
+    "description",
+    "default",
+    "examples",
+    "$schema",
+    "$comment",
+];
+
+/// Validate `value` against `schema`, returning every violation found.
+///
+/// Each violation is prefixed with a JSON-pointer-style path (`$` for the root,
+/// e.g. `$.steps[2].command`). An empty vector means the value conforms.
+pub fn validate_json_schema(value: &Value, schema: &Value) -> Vec<String> {
+    let mut errors = Vec::new();
+    validate_at("$", value, schema, &mut errors);
+    errors
+}
+
+/// List keywords in `schema` that [`validate_json_schema`] does not enforce.
+///
+/// Each entry is `<path>: <keyword>`, using the same path style as violations.
+/// `additionalProperties` is only supported as `false` (or `true`); a schema
+/// object there is reported as unsupported.
+pub fn unsupported_schema_keywords(schema: &Value) -> Vec<String> {
+    let mut unsupported = Vec::new();
+    collect_unsupported("$", schema, &mut unsupported);
+    unsupported
+}
+
+fn collect_unsupported(path: &str, schema: &Value, unsupported: &mut Vec<String>) {
+    let Some(schema) = schema.as_object() else {
+        return;
+    };
+    for (keyword, value) in schema {
+        let supported = match keyword.as_str() {
+            "additionalProperties" => value.is_boolean(),
+            other => SUPPORTED_KEYWORDS.contains(&other),
+        };
+        if !supported {
+            unsupported.push(format!("{}: {}", path, keyword));
+        }
+    }
+    if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+        for (key, child) in properties {
+            collect_unsupported(&format!("{}.{}", path, key), child, unsupported);
+        }
+    }
+    if let Some(items) = schema.get("items") {
+        collect_unsupported(&format!("{}[]", path), items, unsupported);
+    }
+}
+
+fn validate_at(path: &str, value: &Value, schema: &Value, errors: &mut Vec<String>) {
+    let Some(schema) = schema.as_object() else {
+        return;
+    };
+
+    if let Some(expected) = schema.get("type") {
+        let allowed: Vec<&str> = match expected {
+            Value::String(t) => vec![t.as_str()],
+            Value::Array(types) => types.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        if !allowed.is_empty() && !allowed.iter().any(|t| matches_type(value, t)) {
+            errors.push(format!(
+                "{}: expected type {}, got {}",
+                path,
+                allowed.join(" | "),
+                type_name(value)
+            ));
+            return;
+        }
+    }
+
+    if let Some(Value::Array(options)) = schema.get("enum") {
+        if !options.contains(value) {
+            errors.push(format!(
+                "{}: value {} is not one of the allowed values",
+                path, value
+            ));
+        }
+    }
+
+    if let Some(number) = value.as_f64() {
+        if let Some(min) = schema.get("minimum").and_then(Value::as_f64) {
+            if number < min {
+                errors.push(format!("{}: {} is less than minimum {}", path, number, min));
+            }
+        }
+        if let Some(max) = schema.get("maximum").and_then(Value::as_f64) {
+            if number > max {
+                errors.push(format!(
+                    "{}: {} is greater than maximum {}",
+                    path, number, max
+                ));
+            }
+        }
+    }
+
+    if let Some(object) = value.as_object() {
+        if let Some(Value::Array(required)) = schema.get("required") {
+            for field in required.iter().filter_map(Value::as_str) {
+                if !object.contains_key(field) {
+                    errors.push(format!("{}: missing required field '{}'", path, field));
+                }
+            }
+        }
+
+        let properties = schema.get("properties").and_then(Value::as_object);
+        for (key, child) in object {
+            let child_path = format!("{}.{}", path, key);
+            match properties.and_then(|p| p.get(key)) {
+                Some(child_schema) => validate_at(&child_path, child, child_schema, errors),
+                None => {
+                    if schema.get("additionalProperties") == Some(&Value::Bool(false)) {
+                        errors.push(format!("{}: unexpected field '{}'", path, key));
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some(items) = value.as_array() {
+        if let Some(min) = schema.get("minItems").and_then(Value::as_u64) {
+            if (items.len() as u64) < min {
+                errors.push(format!(
+                    "{}: expected at least {} items, got {}",
+                    path,
+                    min,
+                    items.len()
+                ));
+            }
+        }
+        if let Some(max) = schema.get("maxItems").and_then(Value::as_u64) {
+            if (items.len() as u64) > max {
+                errors.push(format!(
+                    "{}: expected at most {} items, got {}",
+                    path,
+                    max,
+                    items.len()
+                ));
+            }
+        }
+        if let Some(item_schema) = schema.get("items") {
+            for (index, item) in items.iter().enumerate() {
+                validate_at(&format!("{}[{}]", path, index), item, item_schema, errors);
+            }
+        }
+    }
+}
+
+fn matches_type(value: &Value, expected: &str) -> bool {
+    match expected {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        _ => true,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn task_schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "title": {"type": "string"},
+                "difficulty": {"type": "string", "enum": ["easy", "medium", "hard"]},
+                "steps": {"type": "array", "items": {"type": "string"}, "minItems": 1},
+                "score": {"type": "number", "minimum": 0, "maximum": 1}
+            },
+            "required": ["title", "difficulty"],
+            "additionalProperties": false
+        })
+    }
+
+    #[test]
+    fn test_conforming_value_has_no_errors() {
+        let value = json!({"title": "t", "difficulty": "easy", "steps": ["a"], "score": 0.5});
+        assert!(validate_json_schema(&value, &task_schema()).is_empty());
+    }
+
+    #[test]
+    fn test_reports_each_violation_with_path() {
+        let value = json!({"difficulty": "extreme", "steps": [1], "score": 2, "extra": true});
+        let errors = validate_json_schema(&value, &task_schema());
+
+        assert!(errors
+            .iter()
+            .any(|e| e.contains("missing required field 'title'")));
+        assert!(errors.iter().any(|e| e.starts_with("$.difficulty")));
+        assert!(errors.iter().any(|e| e.starts_with("$.steps[0]")));
+        assert!(errors.iter().any(|e| e.contains("greater than maximum")));
+        assert!(errors
+            .iter()
+            .any(|e| e.contains("unexpected field 'extra'")));
+    }
+
+    #[test]
+    fn test_unsupported_keywords_are_reported() {
+        assert!(unsupported_schema_keywords(&task_schema()).is_empty());
+
+        let schema = json!({
+            "type": "object",
+            "description": "a task",
+            "properties": {
+                "id": {"type": "string", "pattern": "^[a-z]+$"},
+                "tags": {"type": "array", "items": {"type": "string", "minLength": 1}}
+            },
+            "additionalProperties": {"type": "string"},
+            "oneOf": []
+        });
+        let mut unsupported = unsupported_schema_keywords(&schema);
+        unsupported.sort();
+        assert_eq!(
+            unsupported,
+            vec![
+                "$.id: pattern",
+                "$.tags[]: minLength",
+                "$: additionalProperties",
+                "$: oneOf",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_type_mismatch_at_root() {
+        let errors = validate_json_schema(&json!("text"), &task_schema());
+        assert_eq!(
+            errors,
+            vec!["$: expected type object, got string".to_string()]
+        );
+    }
+}
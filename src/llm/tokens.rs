@@ -0,0 +1,147 @@
+//! Token counting for context-window budgeting.
+//!
+//! Counts are estimates suitable for deciding whether a prompt fits a model's
+//! context window, not for billing. OpenAI-style models use a pre-tokenizer
+//! modelled on tiktoken's `cl100k` splitting rules; other model families fall
+//! back to a characters-per-token heuristic.
+
+use crate::llm::Message;
+
+/// Average characters per token used by the heuristic fallback.
+const HEURISTIC_CHARS_PER_TOKEN: usize = 4;
+
+/// Characters a BPE vocabulary typically merges into one token for a word.
+const BPE_CHARS_PER_WORD_TOKEN: usize = 6;
+
+/// Digits tiktoken groups into one token.
+const BPE_DIGITS_PER_TOKEN: usize = 3;
+
+/// Fixed per-message overhead of the chat format (role and separators).
+const MESSAGE_OVERHEAD_TOKENS: usize = 4;
+
+/// Model identifier fragments that use tiktoken-style tokenization.
+const OPENAI_MODEL_PATTERNS: &[&str] = &["openai/", "gpt-", "gpt4", "o1-", "o3-", "o4-", "codex"];
+
+/// Tokenizer family used to estimate token counts for a model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenizerFamily {
+    /// tiktoken-style byte-pair encoding (OpenAI models).
+    Bpe,
+    /// Characters-per-token heuristic for all other models.
+    Heuristic,
+}
+
+impl TokenizerFamily {
+    /// Select the tokenizer family for a model identifier.
+    pub fn for_model(model: &str) -> Self {
+        let model = model.to_lowercase();
+        if OPENAI_MODEL_PATTERNS.iter().any(|p| model.contains(p)) {
+            Self::Bpe
+        } else {
+            Self::Heuristic
+        }
+    }
+}
+
+/// Estimate the number of tokens `text` occupies for `model`.
+pub fn count_tokens(model: &str, text: &str) -> usize {
+    match TokenizerFamily::for_model(model) {
+        TokenizerFamily::Bpe => count_bpe_tokens(text),
+        TokenizerFamily::Heuristic => text.chars().count().div_ceil(HEURISTIC_CHARS_PER_TOKEN),
+    }
+}
+
+/// Estimate tokens by splitting text the way tiktoken's pre-tokenizer does:
+/// letter runs (absorbing one leading space), digit groups, punctuation and
+/// newlines each become separate pieces.
+fn count_bpe_tokens(text: &str) -> usize {
+    let mut tokens = 0;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c.is_alphabetic() || (c == ' ' && chars.peek().is_some_and(|n| n.is_alphabetic())) {
+            let mut len = usize::from(c.is_alphabetic());
+            while chars.peek().is_some_and(|n| n.is_alphabetic()) {
+                chars.next();
+                len += 1;
+            }
+            tokens += len.div_ceil(BPE_CHARS_PER_WORD_TOKEN).max(1);
+        } else if c.is_ascii_digit() {
+            let mut len: usize = 1;
+            while chars.peek().is_some_and(|n| n.is_ascii_digit()) {
+                chars.next();
+                len += 1;
+            }
+            tokens += len.div_ceil(BPE_DIGITS_PER_TOKEN);
+        } else if c == ' ' || c == '\t' {
+            // Runs of indentation merge into a single token.
+            while chars.peek().is_some_and(|n| *n == ' ' || *n == '\t') {
+                chars.next();
+            }
+            tokens += 1;
+        } else {
+            tokens += 1;
+        }
+    }
+
+    tokens
+}
+
+impl Message {
+    /// Estimate the tokens this message occupies in a chat request for `model`.
+    pub fn token_count(&self, model: &str) -> usize {
+        MESSAGE_OVERHEAD_TOKENS + count_tokens(model, &self.content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenizer_family_selection() {
+        assert_eq!(
+            TokenizerFamily::for_model("openai/gpt-5.2-codex:nitro"),
+            TokenizerFamily::Bpe
+        );
+        assert_eq!(TokenizerFamily::for_model("gpt-4"), TokenizerFamily::Bpe);
+        assert_eq!(
+            TokenizerFamily::for_model("anthropic/claude-opus-4.5"),
+            TokenizerFamily::Heuristic
+        );
+    }
+
+    #[test]
+    fn test_short_vs_long_strings() {
+        for model in ["gpt-4", "anthropic/claude-opus-4.5"] {
+            assert_eq!(count_tokens(model, ""), 0);
+            let short = count_tokens(model, "Hello, world!");
+            let long = count_tokens(model, &"Hello, world! ".repeat(100));
+            assert!(short > 0);
+            assert!(long > short * 50, "{model}: {short} vs {long}");
+        }
+        assert_eq!(count_tokens("gpt-4", "Hello, world!"), 4);
+    }
+
+    #[test]
+    fn test_counts_are_monotonic_in_prefix_length() {
+        let text = "fn main() {\n    println!(\"value = {}\", 12345);\n}\n";
+        for model in ["gpt-4", "mistral-large"] {
+            let mut previous = 0;
+            for end in 0..=text.len() {
+                let count = count_tokens(model, &text[..end]);
+                assert!(count >= previous, "{model}: count dropped at {end}");
+                previous = count;
+            }
+        }
+    }
+
+    #[test]
+    fn test_message_token_count_includes_overhead() {
+        let message = Message::user("Hello");
+        assert_eq!(
+            message.token_count("gpt-4"),
+            MESSAGE_OVERHEAD_TOKENS + count_tokens("gpt-4", "Hello")
+        );
+    }
+}
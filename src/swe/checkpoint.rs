@@ -0,0 +1,322 @@
+//! Mining checkpoints for resuming interrupted `SweOrchestrator` runs.
+//!
+//! A checkpoint records which PRs have already been processed, how many tasks
+//! were accepted per difficulty, and the accepted task ids. It is rebuilt from
+//! the pipeline event stream and saved periodically, so a restarted run can
+//! feed the processed PRs into `skip_prs` and only mine the remaining quota.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use crate::swe::pipeline::SwePipelineEvent;
+
+/// Buffer size of the event channel feeding the checkpoint writer.
+const CHECKPOINT_CHANNEL_CAPACITY: usize = 1024;
+
+/// Default interval between periodic checkpoint saves.
+pub const DEFAULT_CHECKPOINT_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Persistent progress of a mining session.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MiningCheckpoint {
+    /// PRs that reached a final outcome (filtered out, rejected by a later
+    /// stage, or accepted), as `(repository, pr_number)`.
+    pub processed_prs: BTreeSet<(String, u64)>,
+    /// Accepted task counts keyed by difficulty level.
+    pub per_difficulty: BTreeMap<String, usize>,
+    /// Ids of accepted tasks, in acceptance order.
+    pub accepted_task_ids: Vec<String>,
+    /// When the checkpoint was last updated.
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl MiningCheckpoint {
+    /// Load a checkpoint, returning `None` when the file does not exist.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Option<Self>> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(path)?;
+        let checkpoint = serde_json::from_str(&content)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(Some(checkpoint))
+    }
+
+    /// Atomically write the checkpoint (temp file + rename).
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, json)?;
+        std::fs::rename(&tmp, path)
+    }
+
+    /// Total number of accepted tasks.
+    pub fn accepted(&self) -> usize {
+        self.accepted_task_ids.len()
+    }
+
+    /// Fold a pipeline event into the checkpoint. Returns true if it changed.
+    pub fn record(&mut self, event: &SwePipelineEvent) -> bool {
+        let changed = match event {
+            // Candidates that pass the filter are still in flight; they are
+            // recorded once accepted or rejected by a later stage.
+            SwePipelineEvent::CandidateFiltered {
+                repo,
+                pr_number,
+                accepted: false,
+                ..
+            }
+            | SwePipelineEvent::CandidateRejected {
+                repo, pr_number, ..
+            } => self.processed_prs.insert((repo.clone(), *pr_number)),
+            SwePipelineEvent::TaskAccepted {
+                task_id,
+                repo,
+                pr_number,
+                difficulty,
+            } => {
+                if self.accepted_task_ids.contains(task_id) {
+                    false
+                } else {
+                    self.processed_prs.insert((repo.clone(), *pr_number));
+                    *self.per_difficulty.entry(difficulty.clone()).or_insert(0) += 1;
+                    self.accepted_task_ids.push(task_id.clone());
+                    true
+                }
+            }
+            _ => false,
+        };
+        if changed {
+            self.updated_at = Some(Utc::now());
+        }
+        changed
+    }
+}
+
+/// Background task that folds pipeline events into a checkpoint and saves it.
+///
+/// The checkpoint is written every `interval` while it has unsaved changes,
+/// immediately after each accepted task, and once more when the pipeline
+/// drops its sender. Events can optionally be forwarded to another consumer
+/// (e.g. the event log). Failed periodic saves are logged and retried on the
+/// next change; only the final save reports an error.
+pub struct CheckpointWriter {
+    tx: mpsc::Sender<SwePipelineEvent>,
+    handle: JoinHandle<io::Result<MiningCheckpoint>>,
+}
+
+impl CheckpointWriter {
+    /// Start the writer from an initial checkpoint state.
+    pub fn spawn(
+        path: impl Into<PathBuf>,
+        initial: MiningCheckpoint,
+        interval: Duration,
+        forward: Option<mpsc::Sender<SwePipelineEvent>>,
+    ) -> Self {
+        let path = path.into();
+        let (tx, mut rx) = mpsc::channel::<SwePipelineEvent>(CHECKPOINT_CHANNEL_CAPACITY);
+
+        let handle = tokio::spawn(async move {
+            let mut checkpoint = initial;
+            let mut dirty = false;
+            let mut ticker = tokio::time::interval(interval);
+            ticker.tick().await;
+
+            loop {
+                tokio::select! {
+                    event = rx.recv() => {
+                        let Some(event) = event else { break };
+                        let accepted = matches!(event, SwePipelineEvent::TaskAccepted { .. });
+                        dirty |= checkpoint.record(&event);
+                        if accepted && dirty {
+                            dirty = !save_logged(&checkpoint, &path).await;
+                        }
+                        if let Some(ref forward) = forward {
+                            let _ = forward.send(event).await;
+                        }
+                    }
+                    _ = ticker.tick() => {
+                        if dirty {
+                            dirty = !save_logged(&checkpoint, &path).await;
+                        }
+                    }
+                }
+            }
+
+            save_blocking(checkpoint.clone(), path).await?;
+            Ok(checkpoint)
+        });
+
+        Self { tx, handle }
+    }
+
+    /// Sender to hand to the pipeline.
+    pub fn sender(&self) -> mpsc::Sender<SwePipelineEvent> {
+        self.tx.clone()
+    }
+
+    /// Close the channel, write the final checkpoint and return it.
+    pub async fn finish(self) -> io::Result<MiningCheckpoint> {
+        drop(self.tx);
+        self.handle.await.map_err(io::Error::other)?
+    }
+}
+
+/// Save a snapshot of the checkpoint on the blocking thread pool.
+async fn save_blocking(checkpoint: MiningCheckpoint, path: PathBuf) -> io::Result<()> {
+    tokio::task::spawn_blocking(move || checkpoint.save(&path))
+        .await
+        .map_err(io::Error::other)?
+}
+
+/// Save the checkpoint, logging failures. Returns true if the save succeeded.
+async fn save_logged(checkpoint: &MiningCheckpoint, path: &Path) -> bool {
+    match save_blocking(checkpoint.clone(), path.to_path_buf()).await {
+        Ok(()) => true,
+        Err(err) => {
+            tracing::warn!(path = %path.display(), error = %err, "Failed to save mining checkpoint");
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filtered(repo: &str, pr_number: u64) -> SwePipelineEvent {
+        SwePipelineEvent::CandidateFiltered {
+            event_id: format!("evt-{pr_number}"),
+            repo: repo.to_string(),
+            pr_number,
+            accepted: false,
+            reasons: vec!["stars 3 below minimum 20".to_string()],
+        }
+    }
+
+    fn accepted(repo: &str, pr_number: u64, difficulty: &str) -> SwePipelineEvent {
+        SwePipelineEvent::TaskAccepted {
+            task_id: format!("{}-{pr_number}", repo.replace('/', "-")),
+            repo: repo.to_string(),
+            pr_number,
+            difficulty: difficulty.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_record_events() {
+        let mut checkpoint = MiningCheckpoint::default();
+        assert!(checkpoint.record(&filtered("acme/widgets", 1)));
+        assert!(!checkpoint.record(&filtered("acme/widgets", 1)));
+        assert!(checkpoint.record(&accepted("acme/widgets", 2, "medium")));
+        assert!(!checkpoint.record(&accepted("acme/widgets", 2, "medium")));
+        assert!(!checkpoint.record(&SwePipelineEvent::PipelineCompleted { emitted: 1 }));
+
+        assert_eq!(checkpoint.processed_prs.len(), 2);
+        assert_eq!(checkpoint.per_difficulty.get("medium"), Some(&1));
+        assert_eq!(checkpoint.accepted(), 1);
+    }
+
+    #[test]
+    fn test_record_skips_in_flight_candidates() {
+        let mut checkpoint = MiningCheckpoint::default();
+        let passed = SwePipelineEvent::CandidateFiltered {
+            event_id: "evt-3".to_string(),
+            repo: "acme/widgets".to_string(),
+            pr_number: 3,
+            accepted: true,
+            reasons: Vec::new(),
+        };
+        assert!(!checkpoint.record(&passed));
+        assert!(checkpoint.processed_prs.is_empty());
+
+        let rejected = SwePipelineEvent::CandidateRejected {
+            repo: "acme/widgets".to_string(),
+            pr_number: 3,
+            stage: "extraction".to_string(),
+            reason: "empty patch".to_string(),
+        };
+        assert!(checkpoint.record(&rejected));
+        assert!(checkpoint
+            .processed_prs
+            .contains(&("acme/widgets".to_string(), 3)));
+    }
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested/checkpoint.json");
+        assert!(MiningCheckpoint::load(&path).unwrap().is_none());
+
+        let mut checkpoint = MiningCheckpoint::default();
+        checkpoint.record(&accepted("acme/widgets", 7, "hard"));
+        checkpoint.save(&path).unwrap();
+
+        assert_eq!(MiningCheckpoint::load(&path).unwrap(), Some(checkpoint));
+    }
+
+    #[tokio::test]
+    async fn test_writer_saves_and_forwards_events() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("checkpoint.json");
+        let (forward_tx, mut forward_rx) = mpsc::channel(16);
+
+        let writer = CheckpointWriter::spawn(
+            &path,
+            MiningCheckpoint::default(),
+            Duration::from_secs(3600),
+            Some(forward_tx),
+        );
+        let tx = writer.sender();
+        tx.send(filtered("acme/widgets", 1)).await.unwrap();
+        tx.send(accepted("acme/widgets", 2, "easy")).await.unwrap();
+        drop(tx);
+
+        let checkpoint = writer.finish().await.unwrap();
+        assert_eq!(checkpoint.processed_prs.len(), 2);
+        assert_eq!(MiningCheckpoint::load(&path).unwrap(), Some(checkpoint));
+
+        assert!(forward_rx.recv().await.is_some());
+        assert!(forward_rx.recv().await.is_some());
+        assert!(forward_rx.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_writer_survives_save_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        // A directory at the checkpoint path makes every save fail.
+        let path = dir.path().join("checkpoint.json");
+        std::fs::create_dir(&path).unwrap();
+
+        let (forward_tx, mut forward_rx) = mpsc::channel(16);
+
+        let writer = CheckpointWriter::spawn(
+            &path,
+            MiningCheckpoint::default(),
+            Duration::from_secs(3600),
+            Some(forward_tx),
+        );
+        let tx = writer.sender();
+        tx.send(accepted("acme/widgets", 1, "easy")).await.unwrap();
+        tx.send(accepted("acme/widgets", 2, "hard")).await.unwrap();
+        drop(tx);
+
+        // Both events are still forwarded after the first save fails.
+        assert!(forward_rx.recv().await.is_some());
+        assert!(forward_rx.recv().await.is_some());
+        assert!(writer.finish().await.is_err());
+    }
+}
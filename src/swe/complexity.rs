@@ -0,0 +1,108 @@
+//! Heuristic difficulty estimation from patch size.
+//!
+//! Classifying every candidate with an LLM is expensive, yet many PRs are
+//! obviously trivial (a one-line typo fix) or obviously large (a refactor
+//! touching dozens of files). `PatchComplexity` captures cheap size signals
+//! available before extraction so the pipeline can settle those cases
+//! directly and only consult the LLM for borderline patches.
+
+use crate::difficulty::DifficultyLevel;
+
+/// Upper bound on changed lines for an obviously easy patch.
+const OBVIOUS_EASY_MAX_LINES: usize = 10;
+/// Upper bound on files touched for an obviously easy patch.
+const OBVIOUS_EASY_MAX_FILES: usize = 2;
+
+/// Changed lines at or above which a patch is obviously hard.
+const OBVIOUS_HARD_MIN_LINES: usize = 800;
+/// Files touched at or above which a patch is obviously hard.
+const OBVIOUS_HARD_MIN_FILES: usize = 15;
+
+/// Size signals of a pull request's patch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PatchComplexity {
+    /// Number of files touched.
+    pub files_changed: usize,
+    /// Added plus removed lines.
+    pub changed_lines: usize,
+}
+
+impl PatchComplexity {
+    /// Measure from the line and file counts reported for a pull request.
+    pub fn from_counts(files_changed: usize, added_lines: usize, removed_lines: usize) -> Self {
+        Self {
+            files_changed,
+            changed_lines: added_lines + removed_lines,
+        }
+    }
+
+    /// Difficulty when the signals are unambiguous, `None` for borderline patches.
+    pub fn obvious_difficulty(&self) -> Option<DifficultyLevel> {
+        if self.changed_lines >= OBVIOUS_HARD_MIN_LINES
+            || self.files_changed >= OBVIOUS_HARD_MIN_FILES
+        {
+            return Some(DifficultyLevel::Hard);
+        }
+        if self.changed_lines > 0
+            && self.changed_lines <= OBVIOUS_EASY_MAX_LINES
+            && self.files_changed <= OBVIOUS_EASY_MAX_FILES
+        {
+            return Some(DifficultyLevel::Easy);
+        }
+        None
+    }
+}
+
+/// Lowercase name of a difficulty level, as used by triage and targets.
+pub fn difficulty_name(level: DifficultyLevel) -> &'static str {
+    match level {
+        DifficultyLevel::Easy => "easy",
+        DifficultyLevel::Medium => "medium",
+        DifficultyLevel::Hard => "hard",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tiny_patch_is_easy() {
+        assert_eq!(
+            PatchComplexity::from_counts(1, 1, 1).obvious_difficulty(),
+            Some(DifficultyLevel::Easy)
+        );
+    }
+
+    #[test]
+    fn test_huge_patch_is_hard() {
+        assert_eq!(
+            PatchComplexity::from_counts(40, 1500, 600).obvious_difficulty(),
+            Some(DifficultyLevel::Hard)
+        );
+        assert_eq!(
+            PatchComplexity::from_counts(20, 30, 10).obvious_difficulty(),
+            Some(DifficultyLevel::Hard)
+        );
+    }
+
+    #[test]
+    fn test_borderline_patch_defers_to_llm() {
+        assert_eq!(
+            PatchComplexity::from_counts(4, 120, 30).obvious_difficulty(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_from_counts_without_changes_is_not_obvious() {
+        assert_eq!(
+            PatchComplexity::from_counts(0, 0, 0).obvious_difficulty(),
+            None
+        );
+        assert_eq!(
+            PatchComplexity::from_counts(1, 3, 2).obvious_difficulty(),
+            Some(DifficultyLevel::Easy)
+        );
+    }
+}
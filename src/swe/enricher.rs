@@ -16,7 +16,13 @@ pub struct EnrichedPullRequest {
     pub number: u64,
     pub title: String,
     pub body: String,
+    /// Effective language: the file-based language when one could be inferred,
+    /// otherwise the repository-level language.
     pub language: String,
+    /// Primary language reported by GitHub repository metadata.
+    pub repo_language: String,
+    /// Language inferred from the extensions of the changed files.
+    pub file_language: Option<String>,
     pub base_sha: String,
     pub merge_sha: String,
     pub files_changed: usize,
@@ -143,8 +149,25 @@ impl PullRequestEnricher {
             }
         }
 
+        let repo_language = language;
+        let file_language = infer_language_from_files(&changed_files);
+        let language = choose_language(&repo_language, file_language.as_deref());
+        if let Some(ref inferred) = file_language {
+            if !inferred.eq_ignore_ascii_case(&repo_language) {
+                tracing::debug!(
+                    repo = %event.repository, pr = event.pull_number,
+                    repo_language = %repo_language, file_language = %inferred,
+                    "Changed-file language differs from repository language"
+                );
+            }
+        }
+
         let mut metadata = HashMap::new();
         metadata.insert("event_action".to_string(), event.action.clone());
+        metadata.insert("repo_language".to_string(), repo_language.clone());
+        if let Some(ref inferred) = file_language {
+            metadata.insert("file_language".to_string(), inferred.clone());
+        }
         metadata.insert("action_by".to_string(), event.actor.clone());
         metadata.insert("source".to_string(), "gharchive".to_string());
         if self.config.fallback_commits {
@@ -164,6 +187,8 @@ impl PullRequestEnricher {
             title,
             body,
             language,
+            repo_language,
+            file_language,
             base_sha,
             merge_sha,
             files_changed,
@@ -301,3 +326,91 @@ async fn fetch_pr_files_info(
     }
     Ok(info)
 }
+
+/// Map a file path to a language by its extension (lowercase names, matching
+/// `FilterConfig::allowed_languages`).
+fn language_for_path(path: &str) -> Option<&'static str> {
+    let basename = path.rsplit('/').next().unwrap_or(path);
+    let (_, ext) = basename.rsplit_once('.')?;
+    let language = match ext.to_lowercase().as_str() {
+        "py" | "pyi" => "python",
+        "js" | "jsx" | "mjs" | "cjs" => "javascript",
+        "ts" | "tsx" | "mts" | "cts" => "typescript",
+        "go" => "go",
+        "rs" => "rust",
+        "java" => "java",
+        "kt" | "kts" => "kotlin",
+        "rb" => "ruby",
+        "php" => "php",
+        "cs" => "c#",
+        "c" | "h" => "c",
+        "cc" | "cpp" | "cxx" | "hpp" | "hh" => "c++",
+        "swift" => "swift",
+        "scala" => "scala",
+        "sh" | "bash" => "shell",
+        _ => return None,
+    };
+    Some(language)
+}
+
+/// Infer the dominant language of a PR from its changed file extensions.
+///
+/// Files with unrecognised extensions (docs, config, lockfiles) are ignored.
+/// Ties are broken alphabetically so the result is deterministic.
+pub fn infer_language_from_files(files: &[String]) -> Option<String> {
+    let mut counts: HashMap<&'static str, usize> = HashMap::new();
+    for language in files.iter().filter_map(|f| language_for_path(f)) {
+        *counts.entry(language).or_insert(0) += 1;
+    }
+    counts
+        .into_iter()
+        .max_by(|(lang_a, count_a), (lang_b, count_b)| {
+            count_a.cmp(count_b).then_with(|| lang_b.cmp(lang_a))
+        })
+        .map(|(language, _)| language.to_string())
+}
+
+/// Prefer the file-based language over the repository-level one.
+fn choose_language(repo_language: &str, file_language: Option<&str>) -> String {
+    file_language.unwrap_or(repo_language).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths(files: &[&str]) -> Vec<String> {
+        files.iter().map(|f| f.to_string()).collect()
+    }
+
+    #[test]
+    fn test_file_language_preferred_over_repo_language() {
+        let files = paths(&["src/app/main.py", "src/app/utils.py", "tests/test_main.py"]);
+        let file_language = infer_language_from_files(&files);
+
+        assert_eq!(file_language.as_deref(), Some("python"));
+        assert_eq!(choose_language("Shell", file_language.as_deref()), "python");
+    }
+
+    #[test]
+    fn test_repo_language_kept_when_files_unrecognised() {
+        let files = paths(&["README.md", "docs/guide.rst", "Cargo.lock"]);
+        let file_language = infer_language_from_files(&files);
+
+        assert_eq!(file_language, None);
+        assert_eq!(choose_language("rust", file_language.as_deref()), "rust");
+    }
+
+    #[test]
+    fn test_infer_language_majority_and_ties() {
+        let files = paths(&["web/app.ts", "web/view.tsx", "server/main.go"]);
+        assert_eq!(
+            infer_language_from_files(&files).as_deref(),
+            Some("typescript")
+        );
+
+        let tie = paths(&["a.rs", "b.go"]);
+        assert_eq!(infer_language_from_files(&tie).as_deref(), Some("go"));
+        assert_eq!(infer_language_from_files(&[]), None);
+    }
+}
@@ -0,0 +1,144 @@
+//! JSONL event log for SWE pipeline runs.
+//!
+//! Tees every `SwePipelineEvent` of a run into a file, one timestamped record
+//! per line, so the run's timeline can be post-processed after the fact
+//! (per-stage durations, rejection breakdowns, failure debugging).
+
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::{self, Sender};
+use tokio::task::JoinHandle;
+
+use super::pipeline::SwePipelineEvent;
+
+/// Capacity of the channel between the pipeline and the log writer.
+const EVENT_LOG_CHANNEL_CAPACITY: usize = 1024;
+
+/// A single line of the event log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventLogRecord {
+    /// When the writer received the event.
+    pub timestamp: DateTime<Utc>,
+    /// The pipeline event.
+    pub event: SwePipelineEvent,
+}
+
+/// Background writer that appends pipeline events to a JSONL file.
+///
+/// Pass [`EventLogWriter::sender`] as the pipeline's `event_tx`, then call
+/// [`EventLogWriter::finish`] once the run returns to flush the file.
+pub struct EventLogWriter {
+    tx: Sender<SwePipelineEvent>,
+    handle: JoinHandle<std::io::Result<usize>>,
+}
+
+impl EventLogWriter {
+    /// Create (or truncate) the log file and start the background writer.
+    pub fn create(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        let mut writer = BufWriter::new(File::create(path)?);
+
+        let (tx, mut rx) = mpsc::channel::<SwePipelineEvent>(EVENT_LOG_CHANNEL_CAPACITY);
+        // File writes block, so drain the channel on a blocking thread rather
+        // than stalling a runtime worker on every event.
+        let handle = tokio::task::spawn_blocking(move || {
+            let mut written = 0usize;
+            while let Some(event) = rx.blocking_recv() {
+                let record = EventLogRecord {
+                    timestamp: Utc::now(),
+                    event,
+                };
+                serde_json::to_writer(&mut writer, &record)?;
+                writer.write_all(b"\n")?;
+                // Flush per line so the log survives a crash mid-run.
+                writer.flush()?;
+                written += 1;
+            }
+            Ok(written)
+        });
+
+        Ok(Self { tx, handle })
+    }
+
+    /// Sender to hand to the pipeline as its event channel.
+    pub fn sender(&self) -> Sender<SwePipelineEvent> {
+        self.tx.clone()
+    }
+
+    /// Close the channel and wait for all pending events to be written.
+    ///
+    /// Returns the number of records written. All senders obtained from
+    /// [`EventLogWriter::sender`] must be dropped for this to complete.
+    pub async fn finish(self) -> std::io::Result<usize> {
+        drop(self.tx);
+        self.handle.await.map_err(std::io::Error::other)?
+    }
+}
+
+/// Read an event log back into records, skipping blank lines.
+pub fn read_event_log(path: impl AsRef<Path>) -> std::io::Result<Vec<EventLogRecord>> {
+    let content = fs::read_to_string(path)?;
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).map_err(std::io::Error::from))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_events_written_as_jsonl() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("logs").join("events.jsonl");
+
+        let writer = EventLogWriter::create(&path).unwrap();
+        let tx = writer.sender();
+        tx.send(SwePipelineEvent::CollectionStarted { requested: 10 })
+            .await
+            .unwrap();
+        tx.send(SwePipelineEvent::QualityScored {
+            task_id: "owner-repo-1".to_string(),
+            score: 0.8,
+            passed: true,
+        })
+        .await
+        .unwrap();
+        tx.send(SwePipelineEvent::PipelineCompleted { emitted: 1 })
+            .await
+            .unwrap();
+        drop(tx);
+
+        let written = writer.finish().await.unwrap();
+        assert_eq!(written, 3);
+
+        let content = fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 3);
+        for line in &lines {
+            let value: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert!(value.get("timestamp").is_some());
+            assert!(value.get("event").is_some());
+        }
+
+        let records = read_event_log(&path).unwrap();
+        assert!(matches!(
+            records[0].event,
+            SwePipelineEvent::CollectionStarted { requested: 10 }
+        ));
+        assert!(matches!(
+            records[2].event,
+            SwePipelineEvent::PipelineCompleted { emitted: 1 }
+        ));
+        assert!(records[0].timestamp <= records[2].timestamp);
+    }
+}
@@ -5,6 +5,7 @@ use anyhow::Result;
 
 use crate::swe::docker_sandbox::DockerSandbox;
 use crate::swe::{validate_git_ref, SweTask};
+use crate::utils::clock::Clock;
 
 fn github_token() -> Option<String> {
     std::env::var("GITHUB_TOKEN")
@@ -402,8 +403,10 @@ impl SweTask {
         base_commit: &str,
         merge_commit: &str,
         patch: &ExtractedPatch,
+        clock: &dyn Clock,
     ) -> SweTask {
-        let mut task = SweTask::new(format!("{repo}-{pull_number}"), repo.to_string());
+        let mut task =
+            SweTask::new_with_clock(format!("{repo}-{pull_number}"), repo.to_string(), clock);
         task.base_commit = base_commit.to_string();
         task.merge_commit = merge_commit.to_string();
         task.language = language.to_string();
@@ -4,13 +4,22 @@
 pub struct FilterConfig {
     pub min_stars: u32,
     pub min_files: usize,
-    pub max_files: usize,
+    /// Maximum number of files a PR may touch.
+    pub max_files_changed: usize,
     pub min_added_lines: usize,
     pub max_added_lines: usize,
+    /// Minimum patch size (added + removed lines). Rejects trivial one-line fixes.
+    /// Defaults to `min_added_lines` so the default config accepts the same PRs as before.
+    pub min_patch_lines: usize,
+    /// Maximum patch size (added + removed lines). Rejects sprawling refactors.
+    pub max_patch_lines: usize,
     pub allowed_languages: Vec<String>,
     /// Minimum combined length of PR title + body (in characters) to accept a candidate.
     /// PRs with empty or very short descriptions are unlikely to produce good benchmark tasks.
     pub min_description_length: usize,
+    /// Reject PRs that neither reference an issue ("Fixes #N", "Closes #N", ...)
+    /// nor have linked issues from enrichment.
+    pub require_linked_issue: bool,
 }
 
 impl Default for FilterConfig {
@@ -18,9 +27,11 @@ impl Default for FilterConfig {
         Self {
             min_stars: 20,
             min_files: 1,
-            max_files: 50,
+            max_files_changed: 50,
             min_added_lines: 3,
             max_added_lines: 1000,
+            min_patch_lines: 3,
+            max_patch_lines: 2000,
             allowed_languages: vec![
                 "python".to_string(),
                 "javascript".to_string(),
@@ -30,6 +41,7 @@ impl Default for FilterConfig {
                 "java".to_string(),
             ],
             min_description_length: 80,
+            require_linked_issue: false,
         }
     }
 }
@@ -63,9 +75,11 @@ impl SweepFilter {
     /// * `stars` - Repository star count (0 means unknown)
     /// * `files_changed` - Number of files changed in the PR
     /// * `added_lines` - Number of lines added
+    /// * `removed_lines` - Number of lines removed
     /// * `changed_files` - List of changed file paths
     /// * `title` - PR title
     /// * `body` - PR body/description
+    /// * `linked_issues` - Issue numbers linked to the PR during enrichment
     #[allow(clippy::too_many_arguments)]
     pub fn keep_candidate(
         &self,
@@ -73,9 +87,11 @@ impl SweepFilter {
         stars: u32,
         files_changed: usize,
         added_lines: usize,
+        removed_lines: usize,
         changed_files: &[String],
         title: &str,
         body: &str,
+        linked_issues: &[u64],
     ) -> FilterResult {
         let mut reasons = Vec::new();
         let mut score = 1.0f64;
@@ -108,10 +124,10 @@ impl SweepFilter {
         }
 
         // Skip files/lines checks when data is not reliable (enrichment may have failed)
-        if files_changed > self.config.max_files {
+        if files_changed > self.config.max_files_changed {
             reasons.push(format!(
                 "files changed {files_changed} above max {}",
-                self.config.max_files
+                self.config.max_files_changed
             ));
             score -= 0.25;
         }
@@ -132,6 +148,23 @@ impl SweepFilter {
             score -= 0.2;
         }
 
+        let patch_lines = added_lines + removed_lines;
+        if patch_lines > 0 && patch_lines < self.config.min_patch_lines {
+            reasons.push(format!(
+                "patch lines {} below minimum {}",
+                patch_lines, self.config.min_patch_lines
+            ));
+            score -= 0.2;
+        }
+
+        if patch_lines > self.config.max_patch_lines {
+            reasons.push(format!(
+                "patch lines {} above maximum {}",
+                patch_lines, self.config.max_patch_lines
+            ));
+            score -= 0.2;
+        }
+
         if !changed_files.is_empty() && Self::is_docs_only_change(changed_files) {
             reasons.push("all changed files are documentation/config only".to_string());
             score -= 0.3;
@@ -153,6 +186,15 @@ impl SweepFilter {
             score -= 0.4;
         }
 
+        if self.config.require_linked_issue
+            && linked_issues.is_empty()
+            && !Self::references_issue(title)
+            && !Self::references_issue(body)
+        {
+            reasons.push("no linked issue reference".to_string());
+            score -= 0.3;
+        }
+
         // Check for install infrastructure (dependency management files)
         if !changed_files.is_empty() && !Self::has_install_infrastructure(changed_files) {
             reasons.push("no dependency management files detected in changed files".to_string());
@@ -200,6 +242,30 @@ impl SweepFilter {
         })
     }
 
+    /// Check if text contains a GitHub closing keyword followed by an issue
+    /// reference, e.g. "Fixes #123" or "closes #7".
+    fn references_issue(text: &str) -> bool {
+        const KEYWORDS: [&str; 9] = [
+            "fix", "fixes", "fixed", "close", "closes", "closed", "resolve", "resolves", "resolved",
+        ];
+
+        let lower = text.to_lowercase();
+        KEYWORDS.iter().any(|keyword| {
+            let needle = format!("{keyword} #");
+            lower.match_indices(&needle).any(|(start, _)| {
+                let word_start = lower[..start]
+                    .chars()
+                    .next_back()
+                    .is_none_or(|c| !c.is_alphanumeric());
+                let has_number = lower[start + needle.len()..]
+                    .chars()
+                    .next()
+                    .is_some_and(|c| c.is_ascii_digit());
+                word_start && has_number
+            })
+        })
+    }
+
     /// Check if all changed files are test files only.
     ///
     /// PRs that only modify test files cannot produce valid `fail_to_pass` tests
@@ -363,12 +429,14 @@ mod tests {
             100,
             2,
             50,
+            10,
             &[
                 "tests/test_foo.py".to_string(),
                 "tests/test_bar.py".to_string(),
             ],
             "Update test suite",
             "This PR updates the test suite with better coverage for the parser module and adds new edge case tests.",
+            &[],
         );
         assert!(!result.accepted);
         assert!(result.reasons.iter().any(|r| r.contains("test files only")));
@@ -382,12 +450,14 @@ mod tests {
             100,
             3,
             50,
+            10,
             &[
                 "src/parser.py".to_string(),
                 "tests/test_parser.py".to_string(),
             ],
             "Fix parser bug",
             "This PR fixes a critical bug in the parser module where nested expressions were not handled correctly.",
+            &[],
         );
         assert!(result.accepted);
     }
@@ -400,9 +470,11 @@ mod tests {
             100,
             2,
             50,
+            10,
             &["src/main.py".to_string()],
             "Fix bug",
             "Short desc",
+            &[],
         );
         assert!(!result.accepted);
         assert!(result.reasons.iter().any(|r| r.contains("too short")));
@@ -416,11 +488,152 @@ mod tests {
             100,
             2,
             50,
+            10,
             &["README.md".to_string(), "docs/guide.md".to_string()],
             "Update documentation",
             "This PR updates the project documentation with comprehensive guides for new contributors and updated API references.",
+            &[],
         );
         // Should be rejected for docs-only AND no install infrastructure
         assert!(!result.accepted);
     }
+
+    fn patch_size_result(added_lines: usize, removed_lines: usize) -> FilterResult {
+        patch_size_result_with(FilterConfig::default(), added_lines, removed_lines)
+    }
+
+    fn patch_size_result_with(
+        config: FilterConfig,
+        added_lines: usize,
+        removed_lines: usize,
+    ) -> FilterResult {
+        SweepFilter::new(config).keep_candidate(
+            "python",
+            100,
+            1,
+            added_lines,
+            removed_lines,
+            &["src/parser.py".to_string()],
+            "Fix parser bug",
+            "This PR fixes a critical bug in the parser module where nested expressions were not handled correctly.",
+            &[],
+        )
+    }
+
+    #[test]
+    fn test_patch_below_min_rejected() {
+        let config = FilterConfig {
+            min_patch_lines: 5,
+            ..FilterConfig::default()
+        };
+        let result = patch_size_result_with(config, 3, 1);
+        assert!(!result.accepted);
+        assert!(result
+            .reasons
+            .iter()
+            .any(|r| r == "patch lines 4 below minimum 5"));
+    }
+
+    #[test]
+    fn test_default_patch_floor_matches_added_lines_floor() {
+        let result = patch_size_result(3, 0);
+        assert!(result.accepted);
+        assert!(!result.reasons.iter().any(|r| r.starts_with("patch lines")));
+    }
+
+    #[test]
+    fn test_patch_above_max_rejected() {
+        let result = patch_size_result(900, 1500);
+        assert!(!result.accepted);
+        assert!(result
+            .reasons
+            .iter()
+            .any(|r| r == "patch lines 2400 above maximum 2000"));
+    }
+
+    #[test]
+    fn test_patch_in_range_accepted() {
+        let result = patch_size_result(40, 12);
+        assert!(result.accepted);
+        assert!(!result.reasons.iter().any(|r| r.starts_with("patch lines")));
+    }
+
+    #[test]
+    fn test_too_many_files_rejected() {
+        let filter = SweepFilter::new(FilterConfig {
+            max_files_changed: 3,
+            ..FilterConfig::default()
+        });
+        let result = filter.keep_candidate(
+            "python",
+            100,
+            4,
+            40,
+            12,
+            &["src/parser.py".to_string()],
+            "Fix parser bug",
+            "This PR fixes a critical bug in the parser module where nested expressions were not handled correctly.",
+            &[],
+        );
+        assert!(!result.accepted);
+        assert!(result
+            .reasons
+            .iter()
+            .any(|r| r == "files changed 4 above max 3"));
+    }
+
+    fn linked_issue_result(body: &str, linked_issues: &[u64]) -> FilterResult {
+        let filter = SweepFilter::new(FilterConfig {
+            require_linked_issue: true,
+            ..FilterConfig::default()
+        });
+        filter.keep_candidate(
+            "python",
+            100,
+            2,
+            50,
+            10,
+            &["src/parser.py".to_string()],
+            "Fix parser bug",
+            body,
+            linked_issues,
+        )
+    }
+
+    #[test]
+    fn test_require_linked_issue_accepts_reference() {
+        let result = linked_issue_result(
+            "Nested expressions were dropped by the parser when wrapped in parentheses. Fixes #123.",
+            &[],
+        );
+        assert!(result.accepted);
+
+        let result = linked_issue_result(
+            "Nested expressions were dropped by the parser when wrapped in parentheses and brackets.",
+            &[42],
+        );
+        assert!(result.accepted);
+    }
+
+    #[test]
+    fn test_require_linked_issue_rejects_missing_reference() {
+        let result = linked_issue_result(
+            "Nested expressions were dropped by the parser; see the prefixes #section of the docs.",
+            &[],
+        );
+        assert!(!result.accepted);
+        assert!(result
+            .reasons
+            .iter()
+            .any(|r| r == "no linked issue reference"));
+    }
+
+    #[test]
+    fn test_references_issue() {
+        assert!(SweepFilter::references_issue("Closes #7"));
+        assert!(SweepFilter::references_issue("this resolved #12 finally"));
+        assert!(!SweepFilter::references_issue("Fixes # without number"));
+        assert!(!SweepFilter::references_issue("suffixes #3"));
+        assert!(!SweepFilter::references_issue("See #3"));
+    }
 }
@@ -2,6 +2,7 @@
 
 use std::collections::HashMap;
 use std::io::Read;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use chrono::{DateTime, Timelike, Utc};
@@ -39,6 +40,8 @@ pub struct GhArchiveEvent {
 pub struct GhArchiveClient {
     token: Option<String>,
     client: Client,
+    /// When set, events are replayed from this file instead of downloaded.
+    replay_file: Option<PathBuf>,
 }
 
 impl GhArchiveClient {
@@ -49,9 +52,28 @@ impl GhArchiveClient {
                 .timeout(Duration::from_secs(60))
                 .build()
                 .unwrap_or_else(|_| Client::new()),
+            replay_file: None,
         }
     }
 
+    /// Replay events from a previously downloaded archive instead of GH Archive.
+    ///
+    /// Accepts JSONL (one event per line, as served by GH Archive), a JSON array
+    /// of events, or either form gzip-compressed (`.gz`). `fetch_events` then
+    /// returns the file's events regardless of `max_hours_back`, so pipeline
+    /// runs are reproducible offline.
+    pub fn from_file(path: impl Into<PathBuf>) -> Self {
+        Self {
+            replay_file: Some(path.into()),
+            ..Self::new(None)
+        }
+    }
+
+    /// Path of the replay file, if this client replays from disk.
+    pub fn replay_file(&self) -> Option<&Path> {
+        self.replay_file.as_deref()
+    }
+
     pub fn token(&self) -> Option<&str> {
         self.token.as_deref()
     }
@@ -61,6 +83,12 @@ impl GhArchiveClient {
         &self,
         max_hours_back: u32,
     ) -> Result<Vec<GhArchiveEvent>, anyhow::Error> {
+        if let Some(ref path) = self.replay_file {
+            let events = read_archive_file(path).await?;
+            tracing::info!(path = %path.display(), events = events.len(), "Replayed GH Archive events from file");
+            return Ok(events);
+        }
+
         let max_hours = max_hours_back.max(1);
 
         // Build all hour keys
@@ -162,8 +190,43 @@ async fn fetch_hour_events_static(
     .await
     .map_err(|e| anyhow::anyhow!("gzip decode task panicked: {e}"))??;
 
+    Ok(parse_archive_lines(&raw))
+}
+
+/// Read events from a local archive file (JSONL or JSON array, optionally gzipped).
+async fn read_archive_file(path: &Path) -> Result<Vec<GhArchiveEvent>, anyhow::Error> {
+    let bytes = tokio::fs::read(path)
+        .await
+        .map_err(|e| anyhow::anyhow!("failed reading archive file {}: {e}", path.display()))?;
+
+    let raw = if path.extension().is_some_and(|ext| ext == "gz") {
+        let mut decoder = GzDecoder::new(bytes.as_slice());
+        let mut raw = String::new();
+        decoder.read_to_string(&mut raw).map_err(|e| {
+            anyhow::anyhow!("failed to decode archive file {}: {e}", path.display())
+        })?;
+        raw
+    } else {
+        String::from_utf8(bytes)
+            .map_err(|e| anyhow::anyhow!("archive file {} is not UTF-8: {e}", path.display()))?
+    };
+
+    if raw.trim_start().starts_with('[') {
+        let values: Vec<Value> = serde_json::from_str(&raw)
+            .map_err(|e| anyhow::anyhow!("invalid JSON array in {}: {e}", path.display()))?;
+        return Ok(values
+            .iter()
+            .filter_map(parse_github_archive_event)
+            .collect());
+    }
+
+    Ok(parse_archive_lines(&raw))
+}
+
+/// Parse newline-delimited GH Archive events, skipping malformed lines.
+fn parse_archive_lines(raw: &str) -> Vec<GhArchiveEvent> {
     let mut events = Vec::new();
-    for line in raw.lines() {
+    for line in raw.lines().filter(|l| !l.trim().is_empty()) {
         let value: Value = match serde_json::from_str(line) {
             Ok(v) => v,
             Err(err) => {
@@ -175,8 +238,7 @@ async fn fetch_hour_events_static(
             events.push(event);
         }
     }
-
-    Ok(events)
+    events
 }
 
 fn parse_github_archive_event(value: &Value) -> Option<GhArchiveEvent> {
@@ -262,6 +324,12 @@ fn parse_github_archive_event(value: &Value) -> Option<GhArchiveEvent> {
     });
 
     let has_org = value.get("org").is_some();
+    let created_at = value
+        .get("created_at")
+        .and_then(Value::as_str)
+        .and_then(|ts| DateTime::parse_from_rfc3339(ts).ok())
+        .map(|ts| ts.with_timezone(&Utc))
+        .unwrap_or_else(Utc::now);
 
     Some(GhArchiveEvent {
         id: GhArchiveEventId(format!("evt-{id}")),
@@ -279,6 +347,71 @@ fn parse_github_archive_event(value: &Value) -> Option<GhArchiveEvent> {
         stars,
         has_org,
         event_payload: payload,
-        created_at: Utc::now(),
+        created_at,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    const FIXTURE: &str = r#"{"id":"1001","type":"PullRequestEvent","created_at":"2025-03-01T12:00:00Z","actor":{"login":"alice"},"repo":{"name":"acme/widgets"},"org":{"login":"acme"},"payload":{"action":"closed","number":42,"pull_request":{"number":42,"merged":true,"title":"Fix overflow in parser","body":"Fixes #7","merge_commit_sha":"abc123","base":{"sha":"def456"},"head":{"repo":{"language":"Python"}}}}}
+not json
+{"id":1002,"type":"PullRequestEvent","created_at":"2025-03-01T12:05:00Z","actor":{"login":"bob"},"repo":{"name":"acme/gadgets"},"payload":{"action":"opened","pull_request":{"number":7,"merged":false,"title":"Add feature"}}}
+"#;
+
+    #[tokio::test]
+    async fn test_replay_events_from_jsonl_fixture() {
+        let mut file = tempfile::Builder::new().suffix(".json").tempfile().unwrap();
+        file.write_all(FIXTURE.as_bytes()).unwrap();
+
+        let client = GhArchiveClient::from_file(file.path());
+        assert_eq!(client.replay_file(), Some(file.path()));
+
+        let events = client.fetch_events(24).await.unwrap();
+        assert_eq!(events.len(), 2);
+
+        let merged = &events[0];
+        assert_eq!(merged.repository, "acme/widgets");
+        assert_eq!(merged.actor, "alice");
+        assert_eq!(merged.action, "merged");
+        assert_eq!(merged.pull_number, 42);
+        assert_eq!(merged.base_sha, "def456");
+        assert_eq!(merged.merge_sha, "abc123");
+        assert_eq!(merged.language_hint.as_deref(), Some("python"));
+        assert!(merged.has_org);
+        assert_eq!(
+            merged.created_at,
+            DateTime::parse_from_rfc3339("2025-03-01T12:00:00Z").unwrap()
+        );
+
+        assert_eq!(events[1].id.0, "evt-1002");
+        assert_eq!(events[1].action, "opened");
+        assert!(!events[1].has_org);
+    }
+
+    #[tokio::test]
+    async fn test_replay_events_from_gzipped_json_array() {
+        let lines: Vec<Value> = FIXTURE
+            .lines()
+            .filter_map(|l| serde_json::from_str(l).ok())
+            .collect();
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder
+            .write_all(serde_json::to_string(&lines).unwrap().as_bytes())
+            .unwrap();
+        let mut file = tempfile::Builder::new()
+            .suffix(".json.gz")
+            .tempfile()
+            .unwrap();
+        file.write_all(&encoder.finish().unwrap()).unwrap();
+
+        let events = GhArchiveClient::from_file(file.path())
+            .fetch_events(1)
+            .await
+            .unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[1].repository, "acme/gadgets");
+    }
+}
@@ -12,8 +12,13 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
+use crate::utils::clock::{Clock, SystemClock};
+
+pub mod checkpoint;
+pub mod complexity;
 pub mod docker_sandbox;
 pub mod enricher;
+pub mod event_log;
 pub mod extractor;
 pub mod filters;
 pub mod gharchive;
@@ -29,7 +34,10 @@ pub mod test_generator;
 pub mod tool_server;
 pub mod workspace_validator;
 
+pub use checkpoint::{CheckpointWriter, MiningCheckpoint};
+pub use complexity::PatchComplexity;
 pub use enricher::EnrichedPullRequest;
+pub use event_log::{EventLogRecord, EventLogWriter};
 pub use extractor::{ExtractedPatch, PatchExtractor, PatchExtractorConfig};
 pub use filters::{FilterConfig, FilterResult, SweepFilter};
 pub use gharchive::{GhArchiveClient, GhArchiveEvent, GhArchiveEventId};
@@ -46,6 +54,9 @@ pub use workspace_validator::{ValidationOutcome, WorkspaceValidator};
 /// Default output directory for generated SWE workspaces.
 pub const DEFAULT_SWE_OUTPUT_DIR: &str = "./generated-swe";
 
+/// `SweTask::meta` key holding the canary id embedded in an exported task.
+pub const CANARY_META_KEY: &str = "canary_id";
+
 /// Validate a git ref (commit SHA, branch name) to prevent shell injection.
 ///
 /// Accepts hex-only SHAs (short or full) and standard git ref names
@@ -243,6 +254,15 @@ pub enum SweTaskStatus {
 impl SweTask {
     /// Creates a new task with sane defaults.
     pub fn new(id: impl Into<String>, repo: impl Into<String>) -> Self {
+        Self::new_with_clock(id, repo, &SystemClock)
+    }
+
+    /// Creates a new task whose `created_at` is taken from `clock`.
+    pub fn new_with_clock(
+        id: impl Into<String>,
+        repo: impl Into<String>,
+        clock: &dyn Clock,
+    ) -> Self {
         Self {
             id: id.into(),
             repo: repo.into(),
@@ -250,7 +270,7 @@ impl SweTask {
             merge_commit: String::new(),
             language: String::from("unknown"),
             difficulty_score: 1,
-            created_at: Utc::now(),
+            created_at: clock.now(),
             patch: String::new(),
             test_patch: String::new(),
             fail_to_pass: Vec::new(),
@@ -348,11 +368,35 @@ impl SweTask {
         }
         map
     }
+
+    /// Base Docker image for this task, pinned to the language version in
+    /// `install_config` when one is recorded.
+    pub fn base_image(&self) -> Result<String, crate::error::DockerError> {
+        let version = crate::docker::language_family(&self.language)
+            .and_then(|family| self.install_config.get(family))
+            .map(String::as_str);
+        crate::docker::select_base_image(&self.language, version)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::clock::FixedClock;
+    use chrono::TimeZone;
+
+    #[test]
+    fn new_with_fixed_clock_yields_stable_timestamps() {
+        let clock = FixedClock::new(Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap());
+        let first = SweTask::new_with_clock("acme-widgets-1", "acme/widgets", &clock);
+        let second = SweTask::new_with_clock("acme-widgets-1", "acme/widgets", &clock);
+
+        assert_eq!(first.created_at, clock.now());
+        assert_eq!(
+            serde_yaml::to_string(&first).unwrap(),
+            serde_yaml::to_string(&second).unwrap()
+        );
+    }
 
     #[test]
     fn validate_git_ref_accepts_hex_sha() {
@@ -474,4 +518,17 @@ mod tests {
     fn validate_file_path_rejects_null_byte() {
         assert!(validate_file_path("file\0.txt").is_err());
     }
+
+    #[test]
+    fn base_image_uses_install_config_version() {
+        let mut task = SweTask::new("acme-widgets-2", "acme/widgets");
+        task.language = "python".to_string();
+        task.install_config = SweTask::install_defaults("python");
+        task.install_config
+            .insert("python".to_string(), "3.9".to_string());
+        assert_eq!(task.base_image().unwrap(), "swe_forge/python-3.9:latest");
+
+        task.install_config.remove("python");
+        assert_eq!(task.base_image().unwrap(), crate::docker::BASE_PYTHON);
+    }
 }
@@ -2,6 +2,7 @@
 
 use std::collections::{HashMap, HashSet};
 use std::fs;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
@@ -10,9 +11,16 @@ use std::time::Duration;
 
 use crate::export::{DatasetConfig, DatasetManager, HfUploadConfig};
 use crate::llm::LlmProvider;
+use crate::swe::checkpoint::{CheckpointWriter, MiningCheckpoint, DEFAULT_CHECKPOINT_INTERVAL};
+use crate::swe::enricher::EnricherConfig;
+use crate::swe::event_log::EventLogWriter;
 use crate::swe::pipeline::{BenchmarkMetrics, DatasetHandle, ExportConfig, SwePipelineConfig};
 use crate::swe::progress::{ProgressCounters, ProgressMonitor};
 use crate::swe::{SwePipelineRunResult, SweTask};
+use crate::utils::clock::{system_clock, Clock};
+
+/// Default interval between progress log lines during a mining run.
+pub const DEFAULT_PROGRESS_INTERVAL: Duration = Duration::from_secs(30);
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SweRunResult {
@@ -22,6 +30,9 @@ pub struct SweRunResult {
     pub skipped: usize,
     pub finished_at: String,
     pub benchmark_metrics: Option<BenchmarkMetrics>,
+    /// Whether this run resumed from an existing checkpoint.
+    #[serde(default)]
+    pub resumed: bool,
 }
 
 /// Per-difficulty quotas for multi-level mining in a single pipeline run.
@@ -108,6 +119,20 @@ pub struct SweOrchestratorConfig {
     pub concurrency_preclassify: Option<usize>,
     /// Override deep processing backlog multiplier (default: 5).
     pub backlog_multiplier: Option<usize>,
+    /// Write every pipeline event to this JSONL file.
+    pub event_log: Option<String>,
+    /// Persist progress to this checkpoint file and resume from it on restart.
+    pub checkpoint: Option<String>,
+    /// Replay GH Archive events from this file instead of downloading them.
+    pub archive_file: Option<String>,
+    /// Root seed for randomized pipeline steps (random if unset).
+    pub seed: Option<u64>,
+    /// Time source for task timestamps.
+    pub clock: Arc<dyn Clock>,
+    /// How often the background progress monitor logs counters.
+    pub progress_interval: Duration,
+    /// PR enrichment settings (GitHub token, issue-link patterns).
+    pub enricher: EnricherConfig,
 }
 
 impl Default for SweOrchestratorConfig {
@@ -131,6 +156,13 @@ impl Default for SweOrchestratorConfig {
             concurrency_deep: None,
             concurrency_preclassify: None,
             backlog_multiplier: None,
+            event_log: None,
+            checkpoint: None,
+            archive_file: None,
+            seed: None,
+            clock: system_clock(),
+            progress_interval: DEFAULT_PROGRESS_INTERVAL,
+            enricher: EnricherConfig::default(),
         }
     }
 }
@@ -146,22 +178,62 @@ impl SweOrchestrator {
     }
 
     pub async fn mine(&self) -> anyhow::Result<SweRunResult> {
-        let is_multi = self.config.difficulty_targets.is_some();
-
-        let (max_tasks, candidate_multiplier) =
-            if let Some(ref targets) = self.config.difficulty_targets {
-                let total = targets.total_tasks();
-                let has_hard = targets.targets.contains_key("hard");
-                let mult = if has_hard { 50 } else { 30 };
-                tracing::info!(?targets, total, "Starting multi-difficulty mining");
-                (total, mult)
-            } else if self.config.difficulty_filter.as_deref() == Some("hard") {
-                (self.config.max_tasks, 200)
-            } else if self.config.difficulty_filter.is_some() {
-                (self.config.max_tasks, 100)
-            } else {
-                (self.config.max_tasks, 50)
+        let resume_from = match self.config.checkpoint {
+            Some(ref path) => MiningCheckpoint::load(path)?,
+            None => None,
+        };
+        let resumed = resume_from.is_some();
+        let checkpoint = resume_from.unwrap_or_default();
+
+        let mut skip_prs = self.config.skip_prs.clone();
+        skip_prs.extend(checkpoint.processed_prs.iter().cloned());
+        let difficulty_targets = self
+            .config
+            .difficulty_targets
+            .as_ref()
+            .map(|targets| remaining_targets(targets, &checkpoint));
+        let remaining_max_tasks = self.config.max_tasks.saturating_sub(checkpoint.accepted());
+
+        if resumed {
+            tracing::info!(
+                processed_prs = checkpoint.processed_prs.len(),
+                accepted = checkpoint.accepted(),
+                per_difficulty = ?checkpoint.per_difficulty,
+                "Resuming mining from checkpoint"
+            );
+            let quota_met = match difficulty_targets {
+                Some(ref targets) => targets.is_empty(),
+                None => remaining_max_tasks == 0,
             };
+            if quota_met {
+                tracing::info!("Checkpoint already meets the requested quota, nothing to mine");
+                return Ok(SweRunResult {
+                    tasks: Vec::new(),
+                    attempted: 0,
+                    passed: 0,
+                    skipped: 0,
+                    finished_at: chrono::Utc::now().to_rfc3339(),
+                    benchmark_metrics: None,
+                    resumed,
+                });
+            }
+        }
+
+        let is_multi = difficulty_targets.is_some();
+
+        let (max_tasks, candidate_multiplier) = if let Some(ref targets) = difficulty_targets {
+            let total = targets.total_tasks();
+            let has_hard = targets.targets.contains_key("hard");
+            let mult = if has_hard { 50 } else { 30 };
+            tracing::info!(?targets, total, "Starting multi-difficulty mining");
+            (total, mult)
+        } else if self.config.difficulty_filter.as_deref() == Some("hard") {
+            (remaining_max_tasks, 200)
+        } else if self.config.difficulty_filter.is_some() {
+            (remaining_max_tasks, 100)
+        } else {
+            (remaining_max_tasks, 50)
+        };
 
         let pipeline_config = SwePipelineConfig {
             min_stars: self.config.min_stars,
@@ -170,13 +242,13 @@ impl SweOrchestrator {
             max_tasks,
             once: self.config.once,
             validate_docker: self.config.validate_docker,
-            skip_prs: self.config.skip_prs.clone(),
+            skip_prs,
             difficulty_filter: if is_multi {
                 None
             } else {
                 self.config.difficulty_filter.clone()
             },
-            difficulty_targets: self.config.difficulty_targets.clone(),
+            difficulty_targets,
             cache: self.config.cache.clone(),
             mining_image: self.config.mining_image.clone(),
             validate_workspace: self.config.validate_workspace,
@@ -184,6 +256,10 @@ impl SweOrchestrator {
             concurrency_deep: self.config.concurrency_deep,
             concurrency_preclassify: self.config.concurrency_preclassify,
             backlog_multiplier: self.config.backlog_multiplier,
+            archive_file: self.config.archive_file.as_ref().map(PathBuf::from),
+            seed: self.config.seed,
+            clock: self.config.clock.clone(),
+            enricher: self.config.enricher.clone(),
         };
 
         // Real-time export config: tasks are written to disk inside the pipeline worker loop
@@ -206,19 +282,37 @@ impl SweOrchestrator {
             Some(Arc::new(DatasetManager::new(ds_config).await?))
         };
 
-        // Start background progress monitor (logs every 30 seconds)
+        // Start background progress monitor
         let progress_counters = ProgressCounters::new();
         let monitor = ProgressMonitor::start(
             progress_counters.clone(),
             max_tasks,
-            Duration::from_secs(30),
+            self.config.progress_interval,
         );
 
+        let event_log = match self.config.event_log {
+            Some(ref path) => Some(EventLogWriter::create(path)?),
+            None => None,
+        };
+
+        let checkpoint_writer = self.config.checkpoint.as_ref().map(|path| {
+            CheckpointWriter::spawn(
+                path,
+                checkpoint,
+                DEFAULT_CHECKPOINT_INTERVAL,
+                event_log.as_ref().map(EventLogWriter::sender),
+            )
+        });
+        let event_sender = match checkpoint_writer {
+            Some(ref writer) => Some(writer.sender()),
+            None => event_log.as_ref().map(EventLogWriter::sender),
+        };
+
         let pipeline = crate::swe::pipeline::SwePipeline::new(&pipeline_config, self.llm.clone())?;
         let run: SwePipelineRunResult = pipeline
             .run_full_with_progress(
                 &pipeline_config,
-                None,
+                event_sender,
                 Some(export_config),
                 dataset_handle.clone(),
                 Some(progress_counters),
@@ -227,6 +321,25 @@ impl SweOrchestrator {
 
         monitor.stop().await;
 
+        // The checkpoint writer forwards to the event log, so finish it first.
+        if let Some(writer) = checkpoint_writer {
+            match writer.finish().await {
+                Ok(saved) => tracing::info!(
+                    processed_prs = saved.processed_prs.len(),
+                    accepted = saved.accepted(),
+                    "Mining checkpoint saved"
+                ),
+                Err(e) => tracing::warn!(error = %e, "Failed to save mining checkpoint"),
+            }
+        }
+
+        if let Some(writer) = event_log {
+            match writer.finish().await {
+                Ok(written) => tracing::info!(events = written, "Pipeline event log written"),
+                Err(e) => tracing::warn!(error = %e, "Failed to write pipeline event log"),
+            }
+        }
+
         // Finalize dataset: flush remaining shard, write combined parquet, upload splits
         if let Some(ref ds) = dataset_handle {
             match ds.finalize().await {
@@ -272,10 +385,29 @@ impl SweOrchestrator {
             skipped,
             finished_at: run.finished_at.to_rfc3339(),
             benchmark_metrics: run.benchmark_metrics,
+            resumed,
         })
     }
 }
 
+/// Subtract already-accepted per-difficulty counts from the quotas, dropping
+/// levels whose quota is already met.
+fn remaining_targets(
+    targets: &DifficultyTargets,
+    checkpoint: &MiningCheckpoint,
+) -> DifficultyTargets {
+    let targets = targets
+        .targets
+        .iter()
+        .filter_map(|(level, &quota)| {
+            let done = checkpoint.per_difficulty.get(level).copied().unwrap_or(0);
+            let remaining = quota.saturating_sub(done);
+            (remaining > 0).then(|| (level.clone(), remaining))
+        })
+        .collect();
+    DifficultyTargets { targets }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -354,6 +486,8 @@ mod tests {
         assert!(config.difficulty_targets.is_none());
         assert!(config.concurrency_enrich.is_none());
         assert!(config.concurrency_deep.is_none());
+        assert!(config.event_log.is_none());
+        assert!(config.checkpoint.is_none());
     }
 
     #[test]
@@ -365,9 +499,204 @@ mod tests {
             skipped: 5,
             finished_at: "2024-01-01T00:00:00Z".to_string(),
             benchmark_metrics: None,
+            resumed: false,
         };
         let json = serde_json::to_string(&result).unwrap();
         assert!(json.contains("\"attempted\":10"));
         assert!(json.contains("\"passed\":5"));
     }
+
+    #[test]
+    fn test_remaining_targets_subtracts_checkpoint_counts() {
+        let targets = DifficultyTargets::parse("easy:2,medium:3,hard:1").unwrap();
+        let mut checkpoint = MiningCheckpoint::default();
+        checkpoint.per_difficulty.insert("easy".to_string(), 2);
+        checkpoint.per_difficulty.insert("medium".to_string(), 1);
+
+        let remaining = remaining_targets(&targets, &checkpoint);
+        assert_eq!(remaining.targets.get("easy"), None);
+        assert_eq!(remaining.targets.get("medium"), Some(&2));
+        assert_eq!(remaining.targets.get("hard"), Some(&1));
+    }
+
+    /// Enricher that never calls the GitHub API.
+    fn offline_enricher() -> EnricherConfig {
+        EnricherConfig {
+            github_token: None,
+            ..EnricherConfig::default()
+        }
+    }
+
+    fn merged_pr_event(repo: &str, number: u64) -> String {
+        serde_json::json!({
+            "id": number.to_string(),
+            "type": "PullRequestEvent",
+            "actor": {"login": "alice"},
+            "repo": {"name": repo},
+            "org": {"login": "acme"},
+            "payload": {
+                "action": "closed",
+                "pull_request": {
+                    "number": number,
+                    "merged": true,
+                    "title": "Fix overflow in parser",
+                    "merge_commit_sha": "abc123",
+                    "base": {"sha": "def456"}
+                }
+            }
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_resume_skips_checkpointed_prs() {
+        use crate::llm::MockLlmProvider;
+        use crate::swe::event_log::read_event_log;
+        use crate::swe::pipeline::SwePipelineEvent;
+
+        let dir = tempfile::tempdir().unwrap();
+        let archive_path = dir.path().join("archive.jsonl");
+        std::fs::write(
+            &archive_path,
+            format!(
+                "{}\n{}\n",
+                merged_pr_event("acme/widgets", 1),
+                merged_pr_event("acme/widgets", 2)
+            ),
+        )
+        .unwrap();
+
+        // A previous, interrupted run already processed both PRs.
+        let checkpoint_path = dir.path().join("checkpoint.json");
+        let mut previous = MiningCheckpoint::default();
+        for pr_number in [1, 2] {
+            previous.record(&SwePipelineEvent::CandidateFiltered {
+                event_id: format!("evt-{pr_number}"),
+                repo: "acme/widgets".to_string(),
+                pr_number,
+                accepted: false,
+                reasons: vec!["stars 3 below minimum 20".to_string()],
+            });
+        }
+        previous.save(&checkpoint_path).unwrap();
+
+        let event_log_path = dir.path().join("events.jsonl");
+        let llm = Arc::new(MockLlmProvider::new());
+        let config = SweOrchestratorConfig {
+            output_dir: dir.path().join("out").to_string_lossy().to_string(),
+            max_tasks: 2,
+            validate_workspace: false,
+            event_log: Some(event_log_path.to_string_lossy().to_string()),
+            checkpoint: Some(checkpoint_path.to_string_lossy().to_string()),
+            archive_file: Some(archive_path.to_string_lossy().to_string()),
+            progress_interval: Duration::from_millis(10),
+            enricher: offline_enricher(),
+            ..SweOrchestratorConfig::default()
+        };
+
+        let result = SweOrchestrator::new(llm.clone(), config)
+            .mine()
+            .await
+            .unwrap();
+
+        assert!(result.resumed);
+        assert_eq!(result.attempted, 0);
+        assert!(result.tasks.is_empty());
+        assert_eq!(llm.request_count().await, 0);
+
+        let events = read_event_log(&event_log_path).unwrap();
+        assert!(!events
+            .iter()
+            .any(|r| matches!(r.event, SwePipelineEvent::CandidateFiltered { .. })));
+
+        let saved = MiningCheckpoint::load(&checkpoint_path).unwrap().unwrap();
+        assert_eq!(saved.processed_prs, previous.processed_prs);
+    }
+
+    #[tokio::test]
+    async fn test_resume_reprocesses_unfinished_prs() {
+        use crate::llm::MockLlmProvider;
+        use crate::swe::pipeline::SwePipelineEvent;
+
+        let dir = tempfile::tempdir().unwrap();
+        let archive_path = dir.path().join("archive.jsonl");
+        std::fs::write(
+            &archive_path,
+            format!(
+                "{}\n{}\n",
+                merged_pr_event("acme/widgets", 1),
+                merged_pr_event("acme/widgets", 2)
+            ),
+        )
+        .unwrap();
+
+        // PR 1 passed the filter but the run stopped before it was accepted
+        // or rejected; PR 2 was filtered out.
+        let checkpoint_path = dir.path().join("checkpoint.json");
+        let mut previous = MiningCheckpoint::default();
+        for (pr_number, accepted) in [(1, true), (2, false)] {
+            previous.record(&SwePipelineEvent::CandidateFiltered {
+                event_id: format!("evt-{pr_number}"),
+                repo: "acme/widgets".to_string(),
+                pr_number,
+                accepted,
+                reasons: Vec::new(),
+            });
+        }
+        previous.save(&checkpoint_path).unwrap();
+
+        let config = SweOrchestratorConfig {
+            output_dir: dir.path().join("out").to_string_lossy().to_string(),
+            max_tasks: 2,
+            validate_workspace: false,
+            checkpoint: Some(checkpoint_path.to_string_lossy().to_string()),
+            archive_file: Some(archive_path.to_string_lossy().to_string()),
+            progress_interval: Duration::from_millis(10),
+            enricher: offline_enricher(),
+            ..SweOrchestratorConfig::default()
+        };
+
+        let result = SweOrchestrator::new(Arc::new(MockLlmProvider::new()), config)
+            .mine()
+            .await
+            .unwrap();
+
+        assert!(result.resumed);
+        let metrics = result.benchmark_metrics.unwrap();
+        assert_eq!(metrics.enriched_count, 1);
+        assert_eq!(metrics.enrichment_failed, 0);
+    }
+
+    #[tokio::test]
+    async fn test_resume_with_quota_met_returns_early() {
+        use crate::llm::MockLlmProvider;
+        use crate::swe::pipeline::SwePipelineEvent;
+
+        let dir = tempfile::tempdir().unwrap();
+        let checkpoint_path = dir.path().join("checkpoint.json");
+        let mut previous = MiningCheckpoint::default();
+        previous.record(&SwePipelineEvent::TaskAccepted {
+            task_id: "acme-widgets-1".to_string(),
+            repo: "acme/widgets".to_string(),
+            pr_number: 1,
+            difficulty: "easy".to_string(),
+        });
+        previous.save(&checkpoint_path).unwrap();
+
+        let config = SweOrchestratorConfig {
+            output_dir: dir.path().join("out").to_string_lossy().to_string(),
+            max_tasks: 1,
+            checkpoint: Some(checkpoint_path.to_string_lossy().to_string()),
+            progress_interval: Duration::from_millis(10),
+            enricher: offline_enricher(),
+            ..SweOrchestratorConfig::default()
+        };
+
+        let result = SweOrchestrator::new(Arc::new(MockLlmProvider::new()), config)
+            .mine()
+            .await
+            .unwrap();
+        assert!(result.resumed);
+        assert!(result.tasks.is_empty());
+    }
 }
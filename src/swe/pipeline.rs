@@ -18,17 +18,22 @@ use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc::{self, Sender};
 use tokio::sync::{Mutex, Semaphore};
 
+use crate::anti_hardcoding::{embed_canary, CanaryConfig};
+use crate::difficulty::DifficultyLevel;
 use crate::llm::LlmProvider;
 use crate::swe::{
-    enricher::{EnrichedPullRequest, PullRequestEnricher},
+    complexity::{difficulty_name, PatchComplexity},
+    enricher::{EnrichedPullRequest, EnricherConfig, PullRequestEnricher},
     extractor::{PatchExtractionInput, PatchExtractor, PatchExtractorConfig},
     filters::SweepFilter,
-    gharchive::GhArchiveClient,
+    gharchive::{GhArchiveClient, GhArchiveEvent},
     orchestrator::DifficultyTargets,
     quality::{QualityConfig, QualityScorer},
     test_generator::TestGenerator,
-    SweTask,
+    SweTask, CANARY_META_KEY,
 };
+use crate::utils::clock::{system_clock, Clock};
+use crate::utils::seeding::{random_root_seed, seeded_rng};
 
 /// Configuration for real-time export of tasks to disk as they are accepted.
 #[derive(Debug, Clone)]
@@ -90,6 +95,8 @@ pub enum SwePipelineEvent {
     },
     CandidateFiltered {
         event_id: String,
+        repo: String,
+        pr_number: u64,
         accepted: bool,
         reasons: Vec<String>,
     },
@@ -109,6 +116,19 @@ pub enum SwePipelineEvent {
         passed: bool,
         reason: Option<String>,
     },
+    TaskAccepted {
+        task_id: String,
+        repo: String,
+        pr_number: u64,
+        difficulty: String,
+    },
+    /// A candidate that passed the local filter was dropped by a later stage.
+    CandidateRejected {
+        repo: String,
+        pr_number: u64,
+        stage: String,
+        reason: String,
+    },
     PipelineCompleted {
         emitted: usize,
     },
@@ -141,6 +161,14 @@ pub struct SwePipelineConfig {
     pub concurrency_preclassify: Option<usize>,
     /// Override deep processing backlog multiplier (default: 5).
     pub backlog_multiplier: Option<usize>,
+    /// Replay GH Archive events from this file instead of downloading them.
+    pub archive_file: Option<std::path::PathBuf>,
+    /// Root seed for randomized steps; a random seed is chosen and logged if unset.
+    pub seed: Option<u64>,
+    /// Time source for task timestamps (pin it for reproducible runs).
+    pub clock: Arc<dyn Clock>,
+    /// PR enrichment settings; without a `github_token` no GitHub API calls are made.
+    pub enricher: EnricherConfig,
 }
 
 impl Default for SwePipelineConfig {
@@ -162,6 +190,10 @@ impl Default for SwePipelineConfig {
             concurrency_deep: None,
             concurrency_preclassify: None,
             backlog_multiplier: None,
+            archive_file: None,
+            seed: None,
+            clock: system_clock(),
+            enricher: EnricherConfig::default(),
         }
     }
 }
@@ -185,12 +217,16 @@ pub struct SwePipeline {
     quality: QualityScorer,
     prompt_rewriter: super::PromptRewriter,
     llm: Arc<dyn LlmProvider>,
+    clock: Arc<dyn Clock>,
 }
 
 impl SwePipeline {
     pub fn new(config: &SwePipelineConfig, llm: Arc<dyn LlmProvider>) -> anyhow::Result<Self> {
-        let archive = GhArchiveClient::new(None);
-        let enricher = PullRequestEnricher::with_default()?;
+        let archive = match config.archive_file {
+            Some(ref path) => GhArchiveClient::from_file(path),
+            None => GhArchiveClient::new(None),
+        };
+        let enricher = PullRequestEnricher::new(config.enricher.clone())?;
 
         let mut filter_cfg = crate::swe::filters::FilterConfig::default();
         if !config.languages.is_empty() {
@@ -217,6 +253,7 @@ impl SwePipeline {
             quality,
             prompt_rewriter,
             llm,
+            clock: config.clock.clone(),
         })
     }
 
@@ -287,9 +324,9 @@ impl SwePipeline {
         }
 
         // Shuffle events for diversity, then truncate to candidates limit
-        use rand::seq::SliceRandom;
-        let mut rng = rand::rng();
-        events.shuffle(&mut rng);
+        let root_seed = config.seed.unwrap_or_else(random_root_seed);
+        tracing::info!(seed = root_seed, "Shuffling events with root seed");
+        shuffle_events(&mut events, root_seed);
 
         if config.max_candidates > 0 && events.len() > config.max_candidates {
             events.truncate(config.max_candidates);
@@ -297,28 +334,7 @@ impl SwePipeline {
 
         // Pre-filter events using GH Archive data (no API calls needed)
         let before_prefilter = events.len();
-        events.retain(|e| {
-            // Must have a valid PR number
-            if e.pull_number == 0 {
-                return false;
-            }
-            // Skip already-processed PRs
-            if config
-                .skip_prs
-                .contains(&(e.repository.clone(), e.pull_number))
-            {
-                return false;
-            }
-            // Exclude bots
-            if e.actor.contains("[bot]") || e.actor == "dependabot" {
-                return false;
-            }
-            // Prefer repos with an org (real projects, not personal forks)
-            if !e.has_org {
-                return false;
-            }
-            true
-        });
+        prefilter_events(&mut events, &config.skip_prs);
         tracing::info!(
             before = before_prefilter,
             after = events.len(),
@@ -346,6 +362,7 @@ impl SwePipeline {
         let extractor = &self.extractor;
         let test_generator = &self.test_generator;
         let prompt_rewriter = &self.prompt_rewriter;
+        let clock = self.clock.as_ref();
         let difficulty_filter = config.difficulty_filter.clone();
         let cache = config.cache.clone();
         let difficulty_targets = config.difficulty_targets.clone();
@@ -446,6 +463,7 @@ impl SwePipeline {
                 let cancelled = cancelled.clone();
                 let mining_image = config.mining_image.clone();
                 let pipeline_llm = self.llm.clone();
+                let event_tx = event_tx.clone();
                 async move {
                     // Helper: check if all quotas are met (multi-target mode)
                     let all_targets_met = |per_diff: &HashMap<String, usize>, dt: &Option<DifficultyTargets>| -> bool {
@@ -539,14 +557,27 @@ impl SwePipeline {
                         enriched.stars,
                         enriched.files_changed,
                         added_lines,
+                        enriched.removed_lines,
                         &enriched.changed_files,
                         &enriched.title,
                         &enriched.body,
+                        &enriched.linked_issues,
                     );
                     filtered_count.fetch_add(1, Ordering::Relaxed);
                     if let Some(ref p) = progress {
                         p.filtered.fetch_add(1, Ordering::Relaxed);
                     }
+                    emit(
+                        &event_tx,
+                        SwePipelineEvent::CandidateFiltered {
+                            event_id: event.id.0.clone(),
+                            repo: enriched.repository.clone(),
+                            pr_number: enriched.number,
+                            accepted: filter_result.accepted,
+                            reasons: filter_result.reasons.clone(),
+                        },
+                    )
+                    .await;
                     if filter_result.accepted {
                         filter_passed_m.fetch_add(1, Ordering::Relaxed);
                     } else {
@@ -581,6 +612,15 @@ impl SwePipeline {
                         &enriched.repository, enriched.number
                     ).await;
 
+                    // Obvious easy/hard patches are classified from size alone.
+                    let obvious_level = if dt.is_some() || df.is_some() {
+                        PatchComplexity::from_counts(
+                            enriched.files_changed, enriched.added_lines, enriched.removed_lines,
+                        ).obvious_difficulty()
+                    } else {
+                        None
+                    };
+
                     let triage_result: Option<String> = if let Some(cached) = cached_triage {
                         tracing::debug!(
                             repo = %enriched.repository, pr = enriched.number,
@@ -597,6 +637,29 @@ impl SwePipeline {
                             _ => {}
                         }
                         Some(cached)
+                    } else if let Some(level) = obvious_level {
+                        let difficulty = difficulty_name(level).to_string();
+                        tracing::debug!(
+                            repo = %enriched.repository, pr = enriched.number,
+                            triage = %difficulty, "Classified by patch complexity heuristic"
+                        );
+                        preclassify_count_m.fetch_add(1, Ordering::Relaxed);
+                        if let Some(ref p) = progress {
+                            p.preclassified.fetch_add(1, Ordering::Relaxed);
+                        }
+                        match level {
+                            DifficultyLevel::Easy => { preclassify_easy_m.fetch_add(1, Ordering::Relaxed); }
+                            DifficultyLevel::Medium => { preclassify_medium_m.fetch_add(1, Ordering::Relaxed); }
+                            DifficultyLevel::Hard => { preclassify_hard_m.fetch_add(1, Ordering::Relaxed); }
+                        }
+                        let _ = cache.upsert(&super::PrCacheEntry {
+                            repo: enriched.repository.clone(),
+                            pr_number: enriched.number,
+                            triage_difficulty: Some(difficulty.clone()),
+                            status: "pre_classified".to_string(),
+                            ..Default::default()
+                        }).await;
+                        Some(difficulty)
                     } else if dt.is_some() || df.is_some() {
                         let _permit = preclassify_sem.acquire().await.unwrap();
                         let filter_val = df.as_deref().unwrap_or("medium");
@@ -655,6 +718,7 @@ impl SwePipeline {
                                         &enriched.repository, enriched.number,
                                         "difficulty not in targets",
                                     ).await;
+                                    emit(&event_tx, rejected(&enriched, "triage", "difficulty not in targets")).await;
                                     return;
                                 }
                                 if current >= quota {
@@ -677,10 +741,11 @@ impl SwePipeline {
                                     triage_difficulty = %triage, filter = %df_val,
                                     skipped = true, "Pre-classification triage"
                                 );
+                                let reason = format!("triage={}, filter={}", triage, df_val);
                                 let _ = cache.mark_rejected(
-                                    &enriched.repository, enriched.number,
-                                    &format!("triage={}, filter={}", triage, df_val),
+                                    &enriched.repository, enriched.number, &reason,
                                 ).await;
+                                emit(&event_tx, rejected(&enriched, "triage", reason)).await;
                                 return;
                             }
                             tracing::info!(
@@ -724,6 +789,7 @@ impl SwePipeline {
                         Err(err) => {
                             extraction_failed_m.fetch_add(1, Ordering::Relaxed);
                             tracing::warn!(repo = %enriched.repository, pr = enriched.number, error = %err, "Extraction failed");
+                            emit(&event_tx, rejected(&enriched, "extraction", err.to_string())).await;
                             return;
                         }
                     };
@@ -735,6 +801,7 @@ impl SwePipeline {
                         &enriched.base_sha,
                         &enriched.merge_sha,
                         &patch,
+                        clock,
                     );
 
                     let raw_body = if enriched.body.is_empty() {
@@ -764,12 +831,14 @@ impl SwePipeline {
                         }
                         Err(err) => {
                             tracing::warn!(task_id = %task.id, error = %err, "Prompt rewrite failed");
+                            emit(&event_tx, rejected(&enriched, "prompt_rewrite", err.to_string())).await;
                             return;
                         }
                     }
 
                     task.meta
                         .insert("pr_title".to_string(), enriched.title.clone());
+                    emit(&event_tx, SwePipelineEvent::TaskExtracted { task_id: task.id.clone() }).await;
 
                     if cancelled.load(Ordering::Relaxed) {
                         return;
@@ -781,10 +850,12 @@ impl SwePipeline {
                         match test_generator.ensure_tests(&mut task, &language).await {
                             Ok(_) => {
                                 test_gen_succeeded_m.fetch_add(1, Ordering::Relaxed);
+                                emit(&event_tx, SwePipelineEvent::TestGenerated { task_id: task.id.clone() }).await;
                             }
                             Err(err) => {
                                 test_gen_failed_m.fetch_add(1, Ordering::Relaxed);
                                 tracing::warn!(task_id = %task.id, error = %err, "Test generation failed");
+                                emit(&event_tx, rejected(&enriched, "test_generation", err.to_string())).await;
                                 return;
                             }
                         }
@@ -798,6 +869,7 @@ impl SwePipeline {
                         Ok(a) => a,
                         Err(err) => {
                             tracing::warn!(task_id = %task.id, error = %err, "Quality assessment failed");
+                            emit(&event_tx, rejected(&enriched, "quality", err.to_string())).await;
                             return;
                         }
                     };
@@ -809,6 +881,11 @@ impl SwePipeline {
                     }
 
                     let (score, passed) = (assessment.score, assessment.passed);
+                    emit(
+                        &event_tx,
+                        SwePipelineEvent::QualityScored { task_id: task.id.clone(), score, passed },
+                    )
+                    .await;
                     quality_scores_m.lock().await.push(score);
                     if passed {
                         quality_passed_m.fetch_add(1, Ordering::Relaxed);
@@ -870,6 +947,15 @@ impl SwePipeline {
                             match validator.validate(&mut task).await {
                                 Ok(crate::swe::workspace_validator::ValidationOutcome::Passed) => {
                                     validation_passed_m.fetch_add(1, Ordering::Relaxed);
+                                    emit(
+                                        &event_tx,
+                                        SwePipelineEvent::WorkspaceValidated {
+                                            task_id: task.id.clone(),
+                                            passed: true,
+                                            reason: None,
+                                        },
+                                    )
+                                    .await;
                                     tracing::info!(
                                         task_id = %task.id,
                                         "Workspace validation PASSED"
@@ -877,6 +963,15 @@ impl SwePipeline {
                                 }
                                 Ok(crate::swe::workspace_validator::ValidationOutcome::Rejected { reason }) => {
                                     validation_failed_m.fetch_add(1, Ordering::Relaxed);
+                                    emit(
+                                        &event_tx,
+                                        SwePipelineEvent::WorkspaceValidated {
+                                            task_id: task.id.clone(),
+                                            passed: false,
+                                            reason: Some(reason.clone()),
+                                        },
+                                    )
+                                    .await;
                                     tracing::warn!(
                                         task_id = %task.id,
                                         reason = %reason,
@@ -888,15 +983,26 @@ impl SwePipeline {
                                             .and_then(|s| s.parse::<u64>().ok()).unwrap_or(0),
                                         &format!("validation: {}", reason),
                                     ).await;
+                                    emit(&event_tx, rejected(&enriched, "workspace_validation", reason)).await;
                                     return;
                                 }
                                 Err(err) => {
                                     validation_failed_m.fetch_add(1, Ordering::Relaxed);
+                                    emit(
+                                        &event_tx,
+                                        SwePipelineEvent::WorkspaceValidated {
+                                            task_id: task.id.clone(),
+                                            passed: false,
+                                            reason: Some(err.to_string()),
+                                        },
+                                    )
+                                    .await;
                                     tracing::warn!(
                                         task_id = %task.id,
                                         error = %err,
                                         "Workspace validation ERROR"
                                     );
+                                    emit(&event_tx, rejected(&enriched, "workspace_validation", err.to_string())).await;
                                     return;
                                 }
                             }
@@ -910,6 +1016,11 @@ impl SwePipeline {
                             let mut langs = languages_m.lock().await;
                             *langs.entry(task.language.clone()).or_insert(0) += 1;
                         }
+                        // Derive the canary from the root seed so `scan-contamination --seed`
+                        // can regenerate it even if the recorded id is lost.
+                        task.meta
+                            .entry(CANARY_META_KEY.to_string())
+                            .or_insert_with(|| CanaryConfig::generate(&task.id, root_seed).canary_id);
                         task.status = crate::swe::SweTaskStatus::Ready;
 
                         if dt.is_some() {
@@ -961,6 +1072,16 @@ impl SwePipeline {
                             if let Some(ref p) = progress {
                                 p.extracted.fetch_add(1, Ordering::Relaxed);
                             }
+                            emit(
+                                &event_tx,
+                                SwePipelineEvent::TaskAccepted {
+                                    task_id: task.id.clone(),
+                                    repo: enriched.repository.clone(),
+                                    pr_number: enriched.number,
+                                    difficulty: level.clone(),
+                                },
+                            )
+                            .await;
                             tasks_mu.lock().await.push(task);
                             tracing::info!(
                                 difficulty = %level,
@@ -1003,6 +1124,16 @@ impl SwePipeline {
                                 if let Some(ref p) = progress {
                                     p.extracted.fetch_add(1, Ordering::Relaxed);
                                 }
+                                emit(
+                                    &event_tx,
+                                    SwePipelineEvent::TaskAccepted {
+                                        task_id: task.id.clone(),
+                                        repo: enriched.repository.clone(),
+                                        pr_number: enriched.number,
+                                        difficulty: assessment.difficulty_level.clone(),
+                                    },
+                                )
+                                .await;
                                 tasks_mu.lock().await.push(task);
                                 tracing::info!(
                                     completed = prev + 1,
@@ -1011,6 +1142,13 @@ impl SwePipeline {
                                 );
                             }
                         }
+                    } else {
+                        let reason = if passed {
+                            format!("difficulty {} not in targets", assessment.difficulty_level)
+                        } else {
+                            format!("quality score {:.2} below threshold", score)
+                        };
+                        emit(&event_tx, rejected(&enriched, "quality", reason)).await;
                     }
                 }
             })
@@ -1132,6 +1270,37 @@ impl SwePipeline {
     }
 }
 
+/// Shuffle events deterministically from the run's root seed.
+fn shuffle_events(events: &mut [GhArchiveEvent], root_seed: u64) {
+    use rand::seq::SliceRandom;
+    events.shuffle(&mut seeded_rng(root_seed, "swe.pipeline.event_shuffle", 0));
+}
+
+/// Drop events that cannot or should not be processed, using GH Archive data
+/// only (no API calls): missing PR numbers, PRs in `skip_prs`, bot authors and
+/// repos without an org.
+fn prefilter_events(events: &mut Vec<GhArchiveEvent>, skip_prs: &HashSet<(String, u64)>) {
+    events.retain(|e| {
+        // Must have a valid PR number
+        if e.pull_number == 0 {
+            return false;
+        }
+        // Skip already-processed PRs
+        if skip_prs.contains(&(e.repository.clone(), e.pull_number)) {
+            return false;
+        }
+        // Exclude bots
+        if e.actor.contains("[bot]") || e.actor == "dependabot" {
+            return false;
+        }
+        // Prefer repos with an org (real projects, not personal forks)
+        if !e.has_org {
+            return false;
+        }
+        true
+    });
+}
+
 fn infer_added_lines(pr: &EnrichedPullRequest) -> usize {
     pr.added_lines
 }
@@ -1142,11 +1311,31 @@ async fn emit(tx: &Option<mpsc::Sender<SwePipelineEvent>>, event: SwePipelineEve
     }
 }
 
+fn rejected(
+    enriched: &EnrichedPullRequest,
+    stage: &str,
+    reason: impl Into<String>,
+) -> SwePipelineEvent {
+    SwePipelineEvent::CandidateRejected {
+        repo: enriched.repository.clone(),
+        pr_number: enriched.number,
+        stage: stage.to_string(),
+        reason: reason.into(),
+    }
+}
+
 fn export_task_to_disk(task: &SweTask, output_dir: &str) -> anyhow::Result<()> {
     let dir = Path::new(output_dir).join(&task.id);
     fs::create_dir_all(&dir)?;
 
-    let prompt = format!("# {}\n\n{}\n", task.id, task.prompt);
+    let mut prompt = format!("# {}\n\n{}\n", task.id, task.prompt);
+    if let Some(canary_id) = task.meta.get(CANARY_META_KEY) {
+        prompt = embed_canary(
+            &prompt,
+            &CanaryConfig::from_id(canary_id.clone()),
+            "prompt.md",
+        );
+    }
     fs::write(dir.join("prompt.md"), prompt)?;
 
     if !task.original_pr_body.is_empty() {
@@ -1443,6 +1632,72 @@ mod tests {
         let _ = fs::remove_dir_all(&tmp);
     }
 
+    #[test]
+    fn export_task_embeds_recorded_canary() {
+        let tmp = tempfile::tempdir().unwrap();
+        let canary = CanaryConfig::generate("test-export-canary", 7);
+        let mut task = SweTask::new("test-export-canary", "owner/repo");
+        task.prompt = "Fix the bug".to_string();
+        task.meta
+            .insert(CANARY_META_KEY.to_string(), canary.canary_id.clone());
+
+        export_task_to_disk(&task, tmp.path().to_str().unwrap()).unwrap();
+
+        let task_dir = tmp.path().join("test-export-canary");
+        let prompt = fs::read_to_string(task_dir.join("prompt.md")).unwrap();
+        assert!(prompt.contains(&canary.canary_id));
+        let workspace: SweTask =
+            serde_yaml::from_str(&fs::read_to_string(task_dir.join("workspace.yaml")).unwrap())
+                .unwrap();
+        assert_eq!(workspace.meta.get(CANARY_META_KEY), Some(&canary.canary_id));
+    }
+
+    fn archive_event(repo: &str, number: u64) -> GhArchiveEvent {
+        GhArchiveEvent {
+            id: crate::swe::GhArchiveEventId(format!("evt-{number}")),
+            event_type: "PullRequestEvent".to_string(),
+            repository: repo.to_string(),
+            actor: "alice".to_string(),
+            action: "merged".to_string(),
+            pull_number: number,
+            issue_number: None,
+            base_sha: String::new(),
+            merge_sha: String::new(),
+            title: String::new(),
+            body: String::new(),
+            language_hint: None,
+            stars: 0,
+            has_org: true,
+            event_payload: serde_json::Value::Null,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn shuffle_events_is_deterministic_per_seed() {
+        let order = |seed: u64| {
+            let mut events: Vec<GhArchiveEvent> =
+                (1..=20).map(|n| archive_event("acme/widgets", n)).collect();
+            shuffle_events(&mut events, seed);
+            events.iter().map(|e| e.pull_number).collect::<Vec<_>>()
+        };
+
+        assert_eq!(order(42), order(42));
+        assert_ne!(order(42), order(43));
+    }
+
+    #[test]
+    fn prefilter_drops_skipped_prs() {
+        let event = archive_event;
+        let mut events = vec![event("acme/widgets", 1), event("acme/widgets", 2)];
+        let skip: HashSet<(String, u64)> = [("acme/widgets".to_string(), 1)].into_iter().collect();
+
+        prefilter_events(&mut events, &skip);
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].pull_number, 2);
+    }
+
     #[test]
     fn infer_added_lines_returns_pr_value() {
         let pr = EnrichedPullRequest {
@@ -1453,6 +1708,8 @@ mod tests {
             base_sha: String::new(),
             merge_sha: String::new(),
             language: "python".to_string(),
+            repo_language: "python".to_string(),
+            file_language: None,
             files_changed: 1,
             added_lines: 42,
             removed_lines: 10,
@@ -0,0 +1,71 @@
+//! Injectable time source for reproducible artifacts.
+//!
+//! Code that stamps generated artifacts takes a [`Clock`] instead of calling
+//! `Utc::now()` directly, so tests and reproducible runs can pin timestamps
+//! with a [`FixedClock`] while normal runs use [`SystemClock`].
+
+use std::fmt;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+
+/// Source of the current time.
+pub trait Clock: Send + Sync + fmt::Debug {
+    /// Current time in UTC.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Wall-clock time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Clock that always returns the same instant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock {
+    time: DateTime<Utc>,
+}
+
+impl FixedClock {
+    /// Create a clock pinned to `time`.
+    pub fn new(time: DateTime<Utc>) -> Self {
+        Self { time }
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.time
+    }
+}
+
+/// Shared wall-clock instance, the default for configurable components.
+pub fn system_clock() -> Arc<dyn Clock> {
+    Arc::new(SystemClock)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_fixed_clock_is_stable() {
+        let time = Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap();
+        let clock = FixedClock::new(time);
+        assert_eq!(clock.now(), time);
+        assert_eq!(clock.now(), clock.now());
+    }
+
+    #[test]
+    fn test_system_clock_advances() {
+        let clock = SystemClock;
+        let first = clock.now();
+        assert!(clock.now() >= first);
+    }
+}
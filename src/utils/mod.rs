@@ -1,13 +1,18 @@
 //! Shared utility functions for swe_forge.
 //!
 //! This module provides common utilities used across multiple modules,
-//! including JSON extraction from LLM responses.
+//! including JSON extraction from LLM responses, seed derivation and an
+//! injectable clock.
 
+pub mod clock;
 pub mod json_extraction;
+pub mod seeding;
 
+pub use clock::{system_clock, Clock, FixedClock, SystemClock};
 pub use json_extraction::{
     analyze_json_structure, detect_truncated_json, extract_from_generic_code_block,
     extract_from_json_code_block, extract_json_from_response, extract_json_with_regex,
     find_matching_brace, find_matching_bracket, try_extract_json_from_response,
     JsonExtractionError, JsonExtractionResult, JsonStructureAnalysis,
 };
+pub use seeding::{derive_seed, random_root_seed, seeded_rng};